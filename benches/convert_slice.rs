@@ -0,0 +1,54 @@
+//! Compares converting a large batch of readings with `convert` per element
+//! against `convert_slice` and the `convert_units` iterator adapter.
+//!
+//! Run with `cargo bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rustoleum::{convert, convert_slice, ConvertIterator, Unit};
+
+/// Pairs covering the original temperature formulas, the original volume
+/// table, and a conversion through the base unit.
+const PAIRS: [(Unit, Unit); 3] = [
+    (Unit::Fahrenheit, Unit::Kelvin),
+    (Unit::Liters, Unit::Gallons),
+    (Unit::Reaumur, Unit::Rankine),
+];
+
+const READINGS: usize = 1_000_000;
+
+fn readings() -> Vec<f64> {
+    (0..READINGS).map(|i| (i % 2000) as f64 * 0.1 - 40.0).collect()
+}
+
+fn bench_batch(c: &mut Criterion) {
+    let input = readings();
+    let mut output = vec![0.0; input.len()];
+
+    let mut group = c.benchmark_group("convert_batch");
+    group.throughput(Throughput::Elements(READINGS as u64));
+    for (from, to) in PAIRS {
+        let pair = format!("{}->{}", from.symbol(), to.symbol());
+        group.bench_function(BenchmarkId::new("convert_per_element", &pair), |b| {
+            b.iter(|| {
+                for (out, &value) in output.iter_mut().zip(&input) {
+                    *out = convert(black_box(from), black_box(to), value).unwrap();
+                }
+            });
+        });
+        group.bench_function(BenchmarkId::new("convert_slice", &pair), |b| {
+            b.iter(|| convert_slice(black_box(from), black_box(to), &input, &mut output).unwrap());
+        });
+        group.bench_function(BenchmarkId::new("convert_units", &pair), |b| {
+            b.iter(|| {
+                let converted = input.iter().copied().convert_units(black_box(from), black_box(to)).unwrap();
+                for (out, value) in output.iter_mut().zip(converted) {
+                    *out = value;
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_batch);
+criterion_main!(benches);
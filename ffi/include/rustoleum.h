@@ -0,0 +1,102 @@
+/* Generated by cbindgen from rustoleum-ffi. Do not edit. */
+
+#ifndef RUSTOLEUM_H
+#define RUSTOLEUM_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+// Result code returned by every fallible function.
+typedef enum RustoleumStatus {
+  // The call succeeded.
+  RUSTOLEUM_STATUS_OK = 0,
+  // A required pointer argument was null.
+  RUSTOLEUM_STATUS_NULL_POINTER = 1,
+  // A string argument was not valid UTF-8.
+  RUSTOLEUM_STATUS_INVALID_UTF8 = 2,
+  // A unit name or handle is not recognized.
+  RUSTOLEUM_STATUS_UNKNOWN_UNIT = 3,
+  // The units measure different dimensions.
+  RUSTOLEUM_STATUS_INCOMPATIBLE_UNITS = 4,
+  // An answer is not a number.
+  RUSTOLEUM_STATUS_INVALID_NUMBER = 5,
+  // An output buffer is too small for the result.
+  RUSTOLEUM_STATUS_BUFFER_TOO_SMALL = 6,
+} RustoleumStatus;
+
+// The outcome of grading an answer.
+typedef enum RustoleumGrade {
+  // The answer does not match, or either value is not finite.
+  RUSTOLEUM_GRADE_INCORRECT = 0,
+  // The answer matches the expected value to the tenths place.
+  RUSTOLEUM_GRADE_CORRECT = 1,
+} RustoleumGrade;
+
+// A handle to a unit, as written by `rustoleum_unit_from_name`.
+typedef uint8_t RustoleumUnit;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Parses a unit name, symbol, or alias, such as `"gallons"`, `"°F"`, or
+// `"cubic-feet"`, and writes its handle to `unit`.
+//
+// Returns `RUSTOLEUM_STATUS_UNKNOWN_UNIT` if the name is not recognized.
+//
+// # Safety
+//
+// `name` must be a NUL-terminated string and `unit` must point to writable
+// memory for a `RustoleumUnit`.
+enum RustoleumStatus rustoleum_unit_from_name(const char *name, RustoleumUnit *unit);
+
+// Writes the plural name of a unit, such as `"cubic feet"`, to `buffer` as
+// a NUL-terminated string.
+//
+// Returns `RUSTOLEUM_STATUS_BUFFER_TOO_SMALL` without writing anything if
+// the name and its terminator do not fit in `length` bytes.
+//
+// # Safety
+//
+// `buffer` must point to at least `length` writable bytes.
+enum RustoleumStatus rustoleum_unit_name(RustoleumUnit unit, char *buffer, size_t length);
+
+// Converts `value` from one unit to another and writes it to `result`.
+//
+// Returns `RUSTOLEUM_STATUS_INCOMPATIBLE_UNITS` if the units measure
+// different dimensions.
+//
+// # Safety
+//
+// `result` must point to writable memory for a `double`.
+enum RustoleumStatus rustoleum_convert(RustoleumUnit from,
+                                       RustoleumUnit to,
+                                       double value,
+                                       double *result);
+
+// Grades an answer against the expected value by rounding both to the
+// tenths place.
+enum RustoleumGrade rustoleum_grade(double expected, double answer);
+
+// Grades an answer given as text, such as `"0.35"`, reading it exactly as
+// written so that it rounds the way it would on paper, as the `rustoleum`
+// command-line tool does.
+//
+// Returns `RUSTOLEUM_STATUS_INVALID_NUMBER` if the answer is not a number.
+//
+// # Safety
+//
+// `answer` must be a NUL-terminated string and `result` must point to
+// writable memory for a `RustoleumGrade`.
+enum RustoleumStatus rustoleum_grade_text(double expected,
+                                          const char *answer,
+                                          enum RustoleumGrade *result);
+
+// Returns a static, NUL-terminated description of a status code.
+const char *rustoleum_status_message(enum RustoleumStatus status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* RUSTOLEUM_H */
@@ -0,0 +1,266 @@
+//! # Rustoleum C API
+//!
+//! A stable `extern "C"` interface to unit parsing, conversion, and grading,
+//! built as `librustoleum_ffi` (`.so`/`.dylib`/`.dll` and `.a`) for use from
+//! C, C++, and any language with a C foreign-function interface. The header
+//! is `include/rustoleum.h`, generated from this crate with cbindgen.
+//!
+//! Every fallible function returns a [`RustoleumStatus`] and writes its
+//! result through an out-pointer, which is left untouched on error. Units
+//! are passed between calls as [`RustoleumUnit`] handles from
+//! [`rustoleum_unit_from_name`]; handles are only meaningful to the build of
+//! the library that issued them, so store unit names, not handles.
+//!
+//! ```c
+//! RustoleumUnit from, to;
+//! double kelvin;
+//! if (rustoleum_unit_from_name("fahrenheit", &from) == RUSTOLEUM_STATUS_OK &&
+//!     rustoleum_unit_from_name("K", &to) == RUSTOLEUM_STATUS_OK &&
+//!     rustoleum_convert(from, to, 212.0, &kelvin) == RUSTOLEUM_STATUS_OK) {
+//!     printf("%.2f\n", kelvin);
+//! }
+//! ```
+
+use std::ffi::{c_char, CStr};
+use std::str::FromStr;
+
+use rustoleum::{convert, grade, parse_answer, Grade, Unit};
+
+/// Result code returned by every fallible function.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RustoleumStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// A string argument was not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// A unit name or handle is not recognized.
+    UnknownUnit = 3,
+    /// The units measure different dimensions.
+    IncompatibleUnits = 4,
+    /// An answer is not a number.
+    InvalidNumber = 5,
+    /// An output buffer is too small for the result.
+    BufferTooSmall = 6,
+}
+
+/// The outcome of grading an answer.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RustoleumGrade {
+    /// The answer does not match, or either value is not finite.
+    Incorrect = 0,
+    /// The answer matches the expected value to the tenths place.
+    Correct = 1,
+}
+
+impl From<Grade> for RustoleumGrade {
+    fn from(grade: Grade) -> Self {
+        match grade {
+            Grade::Correct => Self::Correct,
+            Grade::Incorrect => Self::Incorrect,
+        }
+    }
+}
+
+/// A handle to a unit, as written by `rustoleum_unit_from_name`.
+pub type RustoleumUnit = u8;
+
+/// Looks up the unit behind a handle.
+fn unit(handle: RustoleumUnit) -> Option<Unit> {
+    Unit::all().iter().copied().find(|&unit| unit as u8 == handle)
+}
+
+/// Borrows a NUL-terminated string argument.
+///
+/// # Safety
+///
+/// `text` must be null or point to a NUL-terminated string.
+unsafe fn text<'a>(text: *const c_char) -> Result<&'a str, RustoleumStatus> {
+    if text.is_null() {
+        return Err(RustoleumStatus::NullPointer);
+    }
+    CStr::from_ptr(text).to_str().map_err(|_| RustoleumStatus::InvalidUtf8)
+}
+
+/// Parses a unit name, symbol, or alias, such as `"gallons"`, `"°F"`, or
+/// `"cubic-feet"`, and writes its handle to `unit`.
+///
+/// Returns `RUSTOLEUM_STATUS_UNKNOWN_UNIT` if the name is not recognized.
+///
+/// # Safety
+///
+/// `name` must be a NUL-terminated string and `unit` must point to writable
+/// memory for a `RustoleumUnit`.
+#[no_mangle]
+pub unsafe extern "C" fn rustoleum_unit_from_name(name: *const c_char, unit: *mut RustoleumUnit) -> RustoleumStatus {
+    if unit.is_null() {
+        return RustoleumStatus::NullPointer;
+    }
+    let name = match text(name) {
+        Ok(name) => name,
+        Err(status) => return status,
+    };
+    match Unit::from_str(name) {
+        Ok(parsed) => {
+            *unit = parsed as u8;
+            RustoleumStatus::Ok
+        }
+        Err(_) => RustoleumStatus::UnknownUnit,
+    }
+}
+
+/// Writes the plural name of a unit, such as `"cubic feet"`, to `buffer` as
+/// a NUL-terminated string.
+///
+/// Returns `RUSTOLEUM_STATUS_BUFFER_TOO_SMALL` without writing anything if
+/// the name and its terminator do not fit in `length` bytes.
+///
+/// # Safety
+///
+/// `buffer` must point to at least `length` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn rustoleum_unit_name(unit: RustoleumUnit, buffer: *mut c_char, length: usize) -> RustoleumStatus {
+    let Some(unit) = self::unit(unit) else {
+        return RustoleumStatus::UnknownUnit;
+    };
+    if buffer.is_null() {
+        return RustoleumStatus::NullPointer;
+    }
+    let name = unit.name(true).as_bytes();
+    if name.len() >= length {
+        return RustoleumStatus::BufferTooSmall;
+    }
+    std::ptr::copy_nonoverlapping(name.as_ptr().cast::<c_char>(), buffer, name.len());
+    *buffer.add(name.len()) = 0;
+    RustoleumStatus::Ok
+}
+
+/// Converts `value` from one unit to another and writes it to `result`.
+///
+/// Returns `RUSTOLEUM_STATUS_INCOMPATIBLE_UNITS` if the units measure
+/// different dimensions.
+///
+/// # Safety
+///
+/// `result` must point to writable memory for a `double`.
+#[no_mangle]
+pub unsafe extern "C" fn rustoleum_convert(
+    from: RustoleumUnit,
+    to: RustoleumUnit,
+    value: f64,
+    result: *mut f64,
+) -> RustoleumStatus {
+    let (Some(from), Some(to)) = (unit(from), unit(to)) else {
+        return RustoleumStatus::UnknownUnit;
+    };
+    if result.is_null() {
+        return RustoleumStatus::NullPointer;
+    }
+    match convert(from, to, value) {
+        Some(converted) => {
+            *result = converted;
+            RustoleumStatus::Ok
+        }
+        None => RustoleumStatus::IncompatibleUnits,
+    }
+}
+
+/// Grades an answer against the expected value by rounding both to the
+/// tenths place.
+#[no_mangle]
+pub extern "C" fn rustoleum_grade(expected: f64, answer: f64) -> RustoleumGrade {
+    grade(expected, answer).into()
+}
+
+/// Grades an answer given as text, such as `"0.35"`, reading it exactly as
+/// written so that it rounds the way it would on paper, as the `rustoleum`
+/// command-line tool does.
+///
+/// Returns `RUSTOLEUM_STATUS_INVALID_NUMBER` if the answer is not a number.
+///
+/// # Safety
+///
+/// `answer` must be a NUL-terminated string and `result` must point to
+/// writable memory for a `RustoleumGrade`.
+#[no_mangle]
+pub unsafe extern "C" fn rustoleum_grade_text(
+    expected: f64,
+    answer: *const c_char,
+    result: *mut RustoleumGrade,
+) -> RustoleumStatus {
+    if result.is_null() {
+        return RustoleumStatus::NullPointer;
+    }
+    let answer = match text(answer) {
+        Ok(answer) => answer,
+        Err(status) => return status,
+    };
+    match parse_answer(answer) {
+        Some(answer) => {
+            *result = grade(expected, answer).into();
+            RustoleumStatus::Ok
+        }
+        None => RustoleumStatus::InvalidNumber,
+    }
+}
+
+/// Returns a static, NUL-terminated description of a status code.
+#[no_mangle]
+pub extern "C" fn rustoleum_status_message(status: RustoleumStatus) -> *const c_char {
+    let message = match status {
+        RustoleumStatus::Ok => c"success",
+        RustoleumStatus::NullPointer => c"a required pointer was null",
+        RustoleumStatus::InvalidUtf8 => c"a string was not valid UTF-8",
+        RustoleumStatus::UnknownUnit => c"unknown unit",
+        RustoleumStatus::IncompatibleUnits => c"the units measure different dimensions",
+        RustoleumStatus::InvalidNumber => c"the answer is not a number",
+        RustoleumStatus::BufferTooSmall => c"the buffer is too small",
+    };
+    message.as_ptr()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    #[test]
+    // test parsing, naming, and converting through handles
+    fn test_units_and_convert() {
+        let (mut celsius, mut kelvin, mut liters) = (0, 0, 0);
+        unsafe {
+            assert_eq!(rustoleum_unit_from_name(c"celsius".as_ptr(), &mut celsius), RustoleumStatus::Ok);
+            assert_eq!(rustoleum_unit_from_name(c"K".as_ptr(), &mut kelvin), RustoleumStatus::Ok);
+            assert_eq!(rustoleum_unit_from_name(c"L".as_ptr(), &mut liters), RustoleumStatus::Ok);
+            assert_eq!(rustoleum_unit_from_name(c"dog".as_ptr(), &mut liters), RustoleumStatus::UnknownUnit);
+            assert_eq!(rustoleum_unit_from_name(ptr::null(), &mut liters), RustoleumStatus::NullPointer);
+
+            let mut result = 0.0;
+            assert_eq!(rustoleum_convert(celsius, kelvin, 70.0, &mut result), RustoleumStatus::Ok);
+            assert_eq!(result, 343.15);
+            assert_eq!(rustoleum_convert(celsius, liters, 1.0, &mut result), RustoleumStatus::IncompatibleUnits);
+            assert_eq!(rustoleum_convert(celsius, u8::MAX, 1.0, &mut result), RustoleumStatus::UnknownUnit);
+
+            let mut buffer = [1 as c_char; 8];
+            assert_eq!(rustoleum_unit_name(kelvin, buffer.as_mut_ptr(), buffer.len()), RustoleumStatus::Ok);
+            assert_eq!(CStr::from_ptr(buffer.as_ptr()), c"kelvins");
+            assert_eq!(rustoleum_unit_name(celsius, buffer.as_mut_ptr(), buffer.len()), RustoleumStatus::BufferTooSmall);
+        }
+    }
+
+    #[test]
+    // test grading numbers and text
+    fn test_grade() {
+        assert_eq!(rustoleum_grade(0.7923, 0.8), RustoleumGrade::Correct);
+        let mut result = RustoleumGrade::Incorrect;
+        unsafe {
+            assert_eq!(rustoleum_grade_text(0.35, c"0.4".as_ptr(), &mut result), RustoleumStatus::Ok);
+            assert_eq!(result, RustoleumGrade::Correct);
+            assert_eq!(rustoleum_grade_text(0.35, c"abc".as_ptr(), &mut result), RustoleumStatus::InvalidNumber);
+            assert_eq!(CStr::from_ptr(rustoleum_status_message(RustoleumStatus::InvalidNumber)), c"the answer is not a number");
+        }
+    }
+}
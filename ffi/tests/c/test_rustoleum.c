@@ -0,0 +1,52 @@
+/* Exercises the C API through the generated header. Built and run by
+ * tests/c_api.rs; exits non-zero on the first failed check. */
+
+#include <math.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "rustoleum.h"
+
+#define CHECK(condition)                                                    \
+    do {                                                                    \
+        if (!(condition)) {                                                 \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, \
+                    #condition);                                            \
+            return 1;                                                       \
+        }                                                                   \
+    } while (0)
+
+int main(void) {
+    RustoleumUnit fahrenheit, kelvin, liters, gallons, unknown = 0;
+    CHECK(rustoleum_unit_from_name("fahrenheit", &fahrenheit) == RUSTOLEUM_STATUS_OK);
+    CHECK(rustoleum_unit_from_name("K", &kelvin) == RUSTOLEUM_STATUS_OK);
+    CHECK(rustoleum_unit_from_name("liters", &liters) == RUSTOLEUM_STATUS_OK);
+    CHECK(rustoleum_unit_from_name("gal", &gallons) == RUSTOLEUM_STATUS_OK);
+    CHECK(rustoleum_unit_from_name("farenheit", &unknown) == RUSTOLEUM_STATUS_UNKNOWN_UNIT);
+    CHECK(rustoleum_unit_from_name(NULL, &unknown) == RUSTOLEUM_STATUS_NULL_POINTER);
+    CHECK(unknown == 0);
+
+    double result = 0.0;
+    CHECK(rustoleum_convert(fahrenheit, kelvin, 212.0, &result) == RUSTOLEUM_STATUS_OK);
+    CHECK(fabs(result - 373.15) < 1e-9);
+    CHECK(rustoleum_convert(liters, gallons, 1.0, &result) == RUSTOLEUM_STATUS_OK);
+    CHECK(result == 0.2641);
+    CHECK(rustoleum_convert(liters, kelvin, 1.0, &result) == RUSTOLEUM_STATUS_INCOMPATIBLE_UNITS);
+    CHECK(result == 0.2641);
+
+    char name[16];
+    CHECK(rustoleum_unit_name(gallons, name, sizeof name) == RUSTOLEUM_STATUS_OK);
+    CHECK(strcmp(name, "gallons") == 0);
+    CHECK(rustoleum_unit_name(gallons, name, 7) == RUSTOLEUM_STATUS_BUFFER_TOO_SMALL);
+
+    CHECK(rustoleum_grade(0.7923, 0.8) == RUSTOLEUM_GRADE_CORRECT);
+    CHECK(rustoleum_grade(0.7923, 0.7) == RUSTOLEUM_GRADE_INCORRECT);
+    RustoleumGrade grade = RUSTOLEUM_GRADE_INCORRECT;
+    CHECK(rustoleum_grade_text(0.35, "0.4", &grade) == RUSTOLEUM_STATUS_OK);
+    CHECK(grade == RUSTOLEUM_GRADE_CORRECT);
+    CHECK(rustoleum_grade_text(0.35, "zero", &grade) == RUSTOLEUM_STATUS_INVALID_NUMBER);
+    CHECK(strcmp(rustoleum_status_message(RUSTOLEUM_STATUS_UNKNOWN_UNIT), "unknown unit") == 0);
+
+    puts("ok");
+    return 0;
+}
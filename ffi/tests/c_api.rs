@@ -0,0 +1,65 @@
+//! Checks the generated header and the library from C.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Path of the checked-in header.
+fn header_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("include/rustoleum.h")
+}
+
+/// Directory the library was built into, e.g. `target/debug`.
+fn library_dir() -> PathBuf {
+    // Integration tests run from `target/<profile>/deps`
+    let exe = env::current_exe().unwrap();
+    exe.parent().and_then(Path::parent).unwrap().to_path_buf()
+}
+
+#[test]
+// test the checked-in header matches the exported API
+fn test_header_is_current() {
+    let crate_dir = env!("CARGO_MANIFEST_DIR");
+    let config = cbindgen::Config::from_file(Path::new(crate_dir).join("cbindgen.toml")).unwrap();
+    let mut generated = Vec::new();
+    cbindgen::generate_with_config(crate_dir, config).unwrap().write(&mut generated);
+    let generated = String::from_utf8(generated).unwrap();
+
+    if env::var_os("RUSTOLEUM_UPDATE_HEADER").is_some() {
+        fs::write(header_path(), &generated).unwrap();
+    }
+    let current = fs::read_to_string(header_path()).unwrap_or_default();
+    assert!(
+        current == generated,
+        "include/rustoleum.h is out of date; regenerate it with RUSTOLEUM_UPDATE_HEADER=1 cargo test -p rustoleum-ffi --test c_api"
+    );
+}
+
+#[test]
+// test a C program compiled against the header and shared library
+fn test_c_program() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let library_dir = library_dir();
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("test_rustoleum");
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+
+    let status = Command::new(&compiler)
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror"])
+        .arg("-I")
+        .arg(crate_dir.join("include"))
+        .arg(crate_dir.join("tests/c/test_rustoleum.c"))
+        .arg("-o")
+        .arg(&program)
+        .arg("-L")
+        .arg(&library_dir)
+        .arg(format!("-Wl,-rpath,{}", library_dir.display()))
+        .args(["-lrustoleum_ffi", "-lm"])
+        .status()
+        .unwrap_or_else(|err| panic!("failed to run {compiler}: {err}"));
+    assert!(status.success(), "compiling the C test program failed");
+
+    let output = Command::new(&program).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+}
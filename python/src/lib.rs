@@ -0,0 +1,382 @@
+//! # Rustoleum for Python
+//!
+//! A Python extension module, `rustoleum`, for checking and analyzing
+//! worksheet answers in notebooks. It calls the same conversion and grading
+//! code as the `rustoleum` command-line tool, so a verdict computed in a
+//! notebook is the verdict the CLI gives.
+//!
+//! Build and install it into the active environment with
+//! [maturin](https://www.maturin.rs):
+//!
+//! ```sh
+//! cd python && maturin develop --release
+//! ```
+//!
+//! ```python
+//! import numpy as np
+//! import rustoleum
+//!
+//! expected = rustoleum.convert("liters", "gallons", 3.0)          # 0.7923
+//! rustoleum.grade(expected, "0.8")                                 # Grade.Correct
+//! rustoleum.convert_array("°F", rustoleum.Unit.Celsius, np.array([32.0, 212.0]))
+//! rustoleum.grade_array(expected, ["0.8", "0.79", 0.7])            # [Correct, Correct, Incorrect]
+//! ```
+//!
+//! NumPy is only needed to pass arrays; lists and tuples of numbers work
+//! without it. Units are limited to the built-in [`rustoleum::Unit`]s; see
+//! `Unit` for what the command line accepts that the module does not.
+
+use std::str::FromStr;
+
+use numpy::{AllowTypeChange, IntoPyArray, PyArrayLike1};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyFloat, PyList, PyString, PyTuple};
+use rustoleum::{Decimal, IncompatibleUnits};
+
+/// A unit of measurement, such as `Unit("gallons")` or `Unit.Celsius`.
+///
+/// Names, symbols, and aliases of the built-in units are accepted in any
+/// case, as `Unit::from_str` parses them. Unlike the command line, the
+/// module has no SI-prefixed units other than the built-in ones (`mL`,
+/// `kg`, ...), no compound units such as `gal/min`, no units from a
+/// definitions file, and no ingredient densities.
+#[pyclass(name = "Unit", module = "rustoleum", frozen, eq, hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct PyUnit(rustoleum::Unit);
+
+#[pymethods]
+impl PyUnit {
+    #[new]
+    fn new(name: &str) -> PyResult<Self> {
+        rustoleum::Unit::from_str(name).map(Self).map_err(|error| match error {
+            rustoleum::UnitParseError::Prefixed { unit, .. } => PyValueError::new_err(format!(
+                "SI-prefixed units such as '{name}' are not supported; convert in '{}' instead",
+                unit.symbol()
+            )),
+            error => PyValueError::new_err(error.to_string()),
+        })
+    }
+
+    /// Every built-in unit, grouped by dimension.
+    #[staticmethod]
+    fn all() -> Vec<Self> {
+        rustoleum::Unit::all().iter().copied().map(Self).collect()
+    }
+
+    /// The plural name, such as `"cubic feet"`.
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.0.name(true)
+    }
+
+    /// The symbol, such as `"ft³"`.
+    #[getter]
+    fn symbol(&self) -> &'static str {
+        self.0.symbol()
+    }
+
+    /// The dimension the unit measures, such as `"volume"`.
+    #[getter]
+    fn dimension(&self) -> String {
+        self.0.dimension().to_string()
+    }
+
+    fn __str__(&self) -> &'static str {
+        self.0.name(true)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Unit.{:?}", self.0)
+    }
+}
+
+/// The outcome of grading an answer; `int(grade)` is 1 when correct, so
+/// grades can be summed.
+#[pyclass(name = "Grade", module = "rustoleum", frozen, eq, eq_int, hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum PyGrade {
+    Incorrect = 0,
+    Correct = 1,
+}
+
+impl From<rustoleum::Grade> for PyGrade {
+    fn from(grade: rustoleum::Grade) -> Self {
+        match grade {
+            rustoleum::Grade::Correct => Self::Correct,
+            rustoleum::Grade::Incorrect => Self::Incorrect,
+        }
+    }
+}
+
+#[pymethods]
+impl PyGrade {
+    fn __bool__(&self) -> bool {
+        *self == Self::Correct
+    }
+
+    fn __str__(&self) -> String {
+        rustoleum::Grade::from(*self).to_string()
+    }
+}
+
+impl From<PyGrade> for rustoleum::Grade {
+    fn from(grade: PyGrade) -> Self {
+        match grade {
+            PyGrade::Correct => Self::Correct,
+            PyGrade::Incorrect => Self::Incorrect,
+        }
+    }
+}
+
+/// A unit argument, given as a `Unit` or by name.
+#[derive(FromPyObject)]
+enum UnitArg {
+    Unit(PyUnit),
+    Name(String),
+}
+
+impl UnitArg {
+    fn resolve(self) -> PyResult<rustoleum::Unit> {
+        match self {
+            Self::Unit(unit) => Ok(unit.0),
+            Self::Name(name) => PyUnit::new(&name).map(|unit| unit.0),
+        }
+    }
+}
+
+/// A student's answer. Text is read exactly as written, as the CLI reads
+/// it; floats by their shortest digits, so `0.35` is 0.35 and not its
+/// binary approximation; and integers and `decimal.Decimal`s exactly.
+enum Answer {
+    Text(String),
+    Float(f64),
+    Decimal(Decimal),
+}
+
+impl<'py> FromPyObject<'_, 'py> for Answer {
+    type Error = PyErr;
+
+    fn extract(answer: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        if let Ok(text) = answer.cast::<PyString>() {
+            Ok(Self::Text(text.to_cow()?.into_owned()))
+        } else if let Ok(float) = answer.cast::<PyFloat>() {
+            Ok(Self::Float(float.value()))
+        } else {
+            answer.extract().map(Self::Decimal)
+        }
+    }
+}
+
+impl Answer {
+    /// Grades the answer; a NaN float, such as a missing value in a data
+    /// frame, is incorrect.
+    fn grade(&self, expected: f64) -> PyResult<PyGrade> {
+        let grade = match self {
+            Self::Text(text) => {
+                let answer = rustoleum::parse_answer(text)
+                    .ok_or_else(|| PyValueError::new_err(format!("Answer is not a number: '{text}'")))?;
+                rustoleum::grade(expected, answer)
+            }
+            Self::Float(answer) => rustoleum::grade(expected, *answer),
+            Self::Decimal(answer) => rustoleum::grade(expected, *answer),
+        };
+        Ok(grade.into())
+    }
+}
+
+/// Checks that two units measure the same dimension.
+fn compatible(from: rustoleum::Unit, to: rustoleum::Unit) -> PyResult<()> {
+    if from.dimension() == to.dimension() {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(IncompatibleUnits { from, to }.to_string()))
+    }
+}
+
+/// Converts a value between two units.
+///
+/// Raises `ValueError` if a unit name is not recognized or the units
+/// measure different dimensions.
+#[pyfunction]
+fn convert(from: UnitArg, to: UnitArg, value: f64) -> PyResult<f64> {
+    let (from, to) = (from.resolve()?, to.resolve()?);
+    compatible(from, to)?;
+    Ok(rustoleum::convert(from, to, value).expect("units are compatible"))
+}
+
+/// Converts every value in a list, tuple, or one-dimensional NumPy array,
+/// returning a list for lists and tuples and a `float64` array for anything
+/// else NumPy accepts, such as a pandas column.
+#[pyfunction]
+fn convert_array<'py>(from: UnitArg, to: UnitArg, values: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let (from, to) = (from.resolve()?, to.resolve()?);
+    let py = values.py();
+    // Without NumPy installed, any other sequence is converted as a list too
+    if values.is_instance_of::<PyList>() || values.is_instance_of::<PyTuple>() || py.import("numpy").is_err() {
+        let values: Vec<f64> = values.extract()?;
+        return Ok(PyList::new(py, convert_slice(from, to, &values)?)?.into_any());
+    }
+    let array = values.extract::<PyArrayLike1<'py, f64, AllowTypeChange>>()?;
+    let converted = match array.as_slice() {
+        Ok(values) => convert_slice(from, to, values)?,
+        Err(_) => convert_slice(from, to, &array.as_array().to_vec())?,
+    };
+    Ok(converted.into_pyarray(py).into_any())
+}
+
+/// Converts a slice of values in one pass with a single resolved conversion.
+fn convert_slice(from: rustoleum::Unit, to: rustoleum::Unit, values: &[f64]) -> PyResult<Vec<f64>> {
+    let mut converted = vec![0.0; values.len()];
+    rustoleum::convert_slice(from, to, values, &mut converted).map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(converted)
+}
+
+/// Grades an answer against the expected value by rounding both to the
+/// tenths place, half away from zero, exactly as the CLI does.
+///
+/// The answer may be text, such as `"0.35"`, or a number. Raises
+/// `ValueError` if text is not a number.
+#[pyfunction]
+fn grade(expected: f64, answer: Answer) -> PyResult<PyGrade> {
+    answer.grade(expected)
+}
+
+/// Grades each answer in a sequence, such as a column of a data frame,
+/// against one expected value or a sequence of the same length.
+#[pyfunction]
+fn grade_array(expected: &Bound<'_, PyAny>, answers: Vec<Answer>) -> PyResult<Vec<PyGrade>> {
+    let expected: Vec<f64> = match expected.extract::<f64>() {
+        Ok(expected) => vec![expected; answers.len()],
+        Err(_) => expected.extract()?,
+    };
+    if expected.len() != answers.len() {
+        return Err(PyValueError::new_err(format!(
+            "Expected {} values but got {} answers",
+            expected.len(),
+            answers.len()
+        )));
+    }
+    expected.iter().zip(&answers).map(|(&expected, answer)| answer.grade(expected)).collect()
+}
+
+/// Rounds a value to the tenths place the way grading does, returning a
+/// `decimal.Decimal`, or `None` if the value is NaN or infinite.
+#[pyfunction]
+fn round_tenths(value: f64) -> Option<Decimal> {
+    rustoleum::round_tenths(value)
+}
+
+/// Unit conversion and worksheet grading.
+#[pymodule]
+#[pyo3(name = "rustoleum")]
+pub fn rustoleum_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyUnit>()?;
+    module.add_class::<PyGrade>()?;
+    let unit = module.py().get_type::<PyUnit>();
+    for &each in rustoleum::Unit::all() {
+        unit.setattr(format!("{each:?}"), PyUnit(each))?;
+    }
+    module.add_function(wrap_pyfunction!(convert, module)?)?;
+    module.add_function(wrap_pyfunction!(convert_array, module)?)?;
+    module.add_function(wrap_pyfunction!(grade, module)?)?;
+    module.add_function(wrap_pyfunction!(grade_array, module)?)?;
+    module.add_function(wrap_pyfunction!(round_tenths, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    /// Runs Python statements with the module imported as `rustoleum`.
+    fn run(code: &std::ffi::CStr) {
+        Python::attach(|py| {
+            let module = PyModule::new(py, "rustoleum").unwrap();
+            rustoleum_module(&module).unwrap();
+            let globals = PyDict::new(py);
+            globals.set_item("rustoleum", module).unwrap();
+            py.run(code, Some(&globals), None).unwrap_or_else(|error| panic!("{error}"));
+        });
+    }
+
+    #[test]
+    // test units parse, compare, and describe themselves
+    fn test_units() {
+        run(c"
+Unit = rustoleum.Unit
+assert Unit('°F') == Unit.Fahrenheit == Unit('fahrenheit')
+assert Unit('cubic-feet').name == 'cubic feet' and Unit.CubicFeet.symbol == 'ft³'
+assert Unit.Liters.dimension == 'volume' and str(Unit.Liters) == 'liters'
+assert repr(Unit('mL')) == 'Unit.Milliliters' and len({Unit('L'), Unit.Liters}) == 1
+assert Unit.Kelvin in Unit.all()
+try:
+    Unit('dog')
+    raise AssertionError('parsed dog')
+except ValueError as error:
+    assert str(error) == \"Unknown unit: 'dog'\"
+try:
+    Unit('kL')
+    raise AssertionError('parsed kL')
+except ValueError as error:
+    assert str(error) == \"SI-prefixed units such as 'kL' are not supported; convert in 'L' instead\"
+");
+    }
+
+    #[test]
+    // test converting values, lists, and tuples
+    fn test_convert() {
+        run(c"
+assert rustoleum.convert('celsius', rustoleum.Unit.Kelvin, 70.0) == 343.15
+assert rustoleum.convert_array('C', 'K', [0, 100.0]) == [273.15, 373.15]
+assert rustoleum.convert_array('L', 'mL', (1.0, 2.5)) == [1000.0, 2500.0]
+try:
+    import numpy
+    converted = rustoleum.convert_array('L', 'mL', numpy.arange(3))
+    assert converted.dtype == numpy.float64 and converted.tolist() == [0.0, 1000.0, 2000.0]
+except ImportError:
+    assert rustoleum.convert_array('L', 'mL', range(3)) == [0.0, 1000.0, 2000.0]
+for convert, args in ((rustoleum.convert, ('C', 'L', 1.0)), (rustoleum.convert, ('C', 'dog', 1.0)),
+                      (rustoleum.convert_array, ('C', 'L', [1.0]))):
+    try:
+        convert(*args)
+        raise AssertionError(args)
+    except ValueError:
+        pass
+");
+        let (celsius, kelvin) = (rustoleum::Unit::Celsius, rustoleum::Unit::Kelvin);
+        Python::attach(|py| {
+            let result = convert(UnitArg::Unit(PyUnit(celsius)), UnitArg::Name("K".into()), 21.5).unwrap();
+            assert_eq!(result, rustoleum::convert(celsius, kelvin, 21.5).unwrap());
+            let values = PyList::new(py, [1.0, 2.0]).unwrap();
+            let converted = convert_array(UnitArg::Unit(PyUnit(celsius)), UnitArg::Unit(PyUnit(kelvin)), &values).unwrap();
+            assert_eq!(converted.extract::<Vec<f64>>().unwrap(), [274.15, 275.15]);
+        });
+    }
+
+    #[test]
+    // test grading matches the library's verdicts for text and numbers
+    fn test_grade() {
+        run(c"
+import decimal
+Grade = rustoleum.Grade
+expected = rustoleum.convert('liters', 'gallons', 3.0)
+assert rustoleum.grade(expected, '0.8') == Grade.Correct
+assert rustoleum.grade(0.35, 0.4) and rustoleum.grade(2.45, '2.5') and rustoleum.grade(21.15, 21.2)
+assert not rustoleum.grade(21.15, 21.1) and str(Grade.Incorrect) == 'incorrect'
+assert rustoleum.grade(1.0, 1) and rustoleum.grade(0.25, decimal.Decimal('0.3'))
+assert rustoleum.grade(1.0, float('nan')) == Grade.Incorrect
+grades = rustoleum.grade_array(expected, ['0.8', '7.9e-1', 0.7])
+assert grades == [Grade.Correct, Grade.Correct, Grade.Incorrect] and sum(map(int, grades)) == 2
+assert rustoleum.grade_array([1.04, 1.05], [1.0, 1.1]) == [Grade.Correct, Grade.Correct]
+assert rustoleum.round_tenths(0.35) == decimal.Decimal('0.4') and rustoleum.round_tenths(float('inf')) is None
+for args in ((1.0, 'abc'), ([1.0], [1.0, 2.0])):
+    try:
+        (rustoleum.grade if isinstance(args[1], str) else rustoleum.grade_array)(*args)
+        raise AssertionError(args)
+    except ValueError:
+        pass
+");
+    }
+}
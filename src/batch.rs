@@ -0,0 +1,178 @@
+//! Converting many values between the same pair of units.
+//!
+//! [`convert_slice`] and [`ConvertIterator::convert_units`] resolve the pair
+//! of units once into a [`Converter`] and then apply it element by element,
+//! instead of matching on the units for every value as
+//! [`convert`](crate::convert) does. The loop over a slice is a plain
+//! multiply-add with no branches, so the compiler can vectorize it.
+
+use std::fmt;
+
+use crate::{Converter, Unit};
+
+/// Error type for [`convert_slice`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceConversionError {
+    /// The units measure different dimensions.
+    Incompatible(Unit, Unit),
+    /// The output slice is not the same length as the input.
+    LengthMismatch {
+        /// Length of the input slice
+        input: usize,
+        /// Length of the output slice
+        output: usize,
+    },
+}
+
+impl fmt::Display for SliceConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Incompatible(from, to) => write!(f, "Cannot convert {from} to {to}"),
+            Self::LengthMismatch { input, output } => {
+                write!(f, "Output has {output} elements but input has {input}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SliceConversionError {}
+
+/// Converts every value in `input` from one unit to another, writing the
+/// results to `output`.
+///
+/// # Errors
+///
+/// Returns [`SliceConversionError::Incompatible`] if the units measure
+/// different dimensions, or [`SliceConversionError::LengthMismatch`] if the
+/// slices differ in length. `output` is left untouched on error.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{convert_slice, Unit};
+///
+/// let fahrenheit = [32.0, 212.0, -40.0];
+/// let mut kelvin = [0.0; 3];
+/// convert_slice(Unit::Fahrenheit, Unit::Kelvin, &fahrenheit, &mut kelvin).unwrap();
+/// assert!((kelvin[1] - 373.15).abs() < 1e-9);
+/// ```
+pub fn convert_slice(from: Unit, to: Unit, input: &[f64], output: &mut [f64]) -> Result<(), SliceConversionError> {
+    let converter = Converter::new(from, to).map_err(|_| SliceConversionError::Incompatible(from, to))?;
+    if input.len() != output.len() {
+        return Err(SliceConversionError::LengthMismatch { input: input.len(), output: output.len() });
+    }
+    let (scale, offset) = (converter.scale(), converter.offset());
+    for (out, value) in output.iter_mut().zip(input) {
+        *out = value * scale + offset;
+    }
+    Ok(())
+}
+
+/// An iterator that converts each value of another iterator between units.
+///
+/// Created by [`ConvertIterator::convert_units`].
+#[derive(Debug, Clone)]
+pub struct ConvertUnits<I> {
+    iter: I,
+    converter: Converter,
+}
+
+impl<I: Iterator<Item = f64>> Iterator for ConvertUnits<I> {
+    type Item = f64;
+
+    #[inline]
+    fn next(&mut self) -> Option<f64> {
+        self.iter.next().map(|value| self.converter.apply(value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = f64>> DoubleEndedIterator for ConvertUnits<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<f64> {
+        self.iter.next_back().map(|value| self.converter.apply(value))
+    }
+}
+
+impl<I: ExactSizeIterator<Item = f64>> ExactSizeIterator for ConvertUnits<I> {}
+
+/// Adds unit conversion to iterators of `f64`.
+pub trait ConvertIterator: Iterator<Item = f64> + Sized {
+    /// Converts each value from one unit to another, resolving the
+    /// conversion once up front.
+    ///
+    /// Returns `None` if the units measure different dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::{ConvertIterator, Unit};
+    ///
+    /// let readings = vec![32.0, 50.0, 212.0];
+    /// let celsius: Vec<f64> = readings
+    ///     .into_iter()
+    ///     .convert_units(Unit::Fahrenheit, Unit::Celsius)
+    ///     .unwrap()
+    ///     .collect();
+    /// assert!((celsius[2] - 100.0).abs() < 1e-9);
+    /// ```
+    fn convert_units(self, from: Unit, to: Unit) -> Option<ConvertUnits<Self>> {
+        Some(ConvertUnits { iter: self, converter: Converter::new(from, to).ok()? })
+    }
+}
+
+impl<I: Iterator<Item = f64>> ConvertIterator for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert;
+    use float_cmp::approx_eq;
+
+    #[test]
+    // test slices convert elementwise, exactly for multiplicative pairs
+    fn test_convert_slice() {
+        let liters = [1.0, 2.5, -3.0, 0.0];
+        let mut gallons = [0.0; 4];
+        convert_slice(Unit::Liters, Unit::Gallons, &liters, &mut gallons).unwrap();
+        let expected: Vec<f64> = liters.iter().map(|&v| convert(Unit::Liters, Unit::Gallons, v).unwrap()).collect();
+        assert_eq!(gallons.to_vec(), expected);
+
+        let fahrenheit = [-459.67, 32.0, 212.0];
+        let mut kelvin = [0.0; 3];
+        convert_slice(Unit::Fahrenheit, Unit::Kelvin, &fahrenheit, &mut kelvin).unwrap();
+        for (result, expected) in kelvin.iter().zip([0.0, 273.15, 373.15]) {
+            assert!(approx_eq!(f64, *result, expected, epsilon = 1e-9), "{result}");
+        }
+    }
+
+    #[test]
+    // test slice errors leave the output untouched
+    fn test_convert_slice_errors() {
+        let mut output = [7.0; 2];
+        assert_eq!(
+            convert_slice(Unit::Liters, Unit::Kelvin, &[1.0, 2.0], &mut output),
+            Err(SliceConversionError::Incompatible(Unit::Liters, Unit::Kelvin))
+        );
+        assert_eq!(
+            convert_slice(Unit::Liters, Unit::Cups, &[1.0], &mut output),
+            Err(SliceConversionError::LengthMismatch { input: 1, output: 2 })
+        );
+        assert_eq!(output, [7.0; 2]);
+    }
+
+    #[test]
+    // test the iterator adapter
+    fn test_convert_units_iterator() {
+        let celsius: Vec<f64> = [0.0, 100.0].into_iter().convert_units(Unit::Celsius, Unit::Kelvin).unwrap().collect();
+        assert_eq!(celsius, vec![273.15, 373.15]);
+        let mut iter = [1.0, 2.0, 3.0].into_iter().convert_units(Unit::Gallons, Unit::Quarts).unwrap();
+        assert_eq!(iter.len(), 3);
+        let last = iter.next_back().unwrap();
+        assert!(approx_eq!(f64, last, 12.0, epsilon = 1e-9));
+        assert!([1.0].into_iter().convert_units(Unit::Gallons, Unit::Kelvin).is_none());
+    }
+}
@@ -0,0 +1,674 @@
+//! Compound units built as products and quotients of simple units.
+//!
+//! A [`CompoundUnit`] such as `gal/min`, `lb/ft³`, or `kg/(m·s²)` is a list
+//! of [`PrefixedUnit`] factors with integer exponents. Two compound units can be
+//! converted when their [`BaseDimensions`] agree; volumes count as length³ and
+//! areas as length², so `gal/min` converts to `m³/s` and `L/s` alike.
+//!
+//! Compound units are parsed from strings:
+//!
+//! - `/` divides, and everything after the first `/` is in the denominator
+//!   (`kg/m/s²` is the same as `kg/(m·s²)`)
+//! - `*`, `·`, or `⋅` multiply
+//! - parentheses group, and may nest (`kg/(m/s)` is `kg·s/m`)
+//! - `^n`, `²`, or `³` raise a factor or a group to a power
+//! - `per` may be written instead of `/` ("miles per hour")
+//! - a few common abbreviations, such as `mph` and `gpm`, are expanded
+//!
+//! Factors are parsed as [`PrefixedUnit`]s, so every unit name, symbol, and
+//! alias is accepted, and metric units may carry an SI prefix (`µm/s`). Temperature factors in a product
+//! or quotient are treated as temperature differences: only their scale is
+//! used, never their offset.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::parse::normalize;
+use crate::{Dimension, Prefix, PrefixedUnit, Unit, UnitParseError, VolumeSystem, LITERS_PER_CUBIC_METER};
+
+/// Exponents of the base quantities a unit is built from.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{BaseDimensions, CompoundUnit};
+///
+/// let flow: CompoundUnit = "gal/min".parse().unwrap();
+/// assert_eq!(flow.dimensions(), BaseDimensions { length: 3, time: -1, ..BaseDimensions::default() });
+/// assert_eq!(flow.dimensions().to_string(), "length³/time");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BaseDimensions {
+    /// Exponent of length
+    pub length: i32,
+    /// Exponent of mass
+    pub mass: i32,
+    /// Exponent of time
+    pub time: i32,
+    /// Exponent of temperature
+    pub temperature: i32,
+    /// Exponent of information (data size)
+    pub information: i32,
+}
+
+impl BaseDimensions {
+    /// Returns true if every exponent is zero.
+    #[must_use]
+    pub const fn is_dimensionless(self) -> bool {
+        self.length == 0 && self.mass == 0 && self.time == 0 && self.temperature == 0 && self.information == 0
+    }
+
+    /// Multiplies every exponent by `power`.
+    const fn pow(self, power: i32) -> Self {
+        Self {
+            length: self.length * power,
+            mass: self.mass * power,
+            time: self.time * power,
+            temperature: self.temperature * power,
+            information: self.information * power,
+        }
+    }
+
+    /// Adds the exponents of two dimensions, as when multiplying units.
+    const fn times(self, other: Self) -> Self {
+        Self {
+            length: self.length + other.length,
+            mass: self.mass + other.mass,
+            time: self.time + other.time,
+            temperature: self.temperature + other.temperature,
+            information: self.information + other.information,
+        }
+    }
+
+    /// Returns each base quantity's name and exponent.
+    fn named(self) -> [(&'static str, i32); 5] {
+        [
+            ("length", self.length),
+            ("mass", self.mass),
+            ("time", self.time),
+            ("temperature", self.temperature),
+            ("information", self.information),
+        ]
+    }
+}
+
+impl fmt::Display for BaseDimensions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_dimensionless() {
+            return f.write_str("dimensionless");
+        }
+        let numerator: Vec<(&str, i32)> = self.named().into_iter().filter(|(_, exp)| *exp > 0).collect();
+        let denominator: Vec<(&str, i32)> =
+            self.named().into_iter().filter(|(_, exp)| *exp < 0).map(|(name, exp)| (name, -exp)).collect();
+        write_quotient(f, &numerator, &denominator)
+    }
+}
+
+impl Dimension {
+    /// Returns the base quantities this dimension is built from, e.g.
+    /// length³ for volume.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::Dimension;
+    ///
+    /// assert_eq!(Dimension::Area.base_dimensions().length, 2);
+    /// ```
+    #[must_use]
+    pub const fn base_dimensions(self) -> BaseDimensions {
+        let none = BaseDimensions { length: 0, mass: 0, time: 0, temperature: 0, information: 0 };
+        match self {
+            Self::Temperature => BaseDimensions { temperature: 1, ..none },
+            Self::Volume => BaseDimensions { length: 3, ..none },
+            Self::Area => BaseDimensions { length: 2, ..none },
+            Self::Length => BaseDimensions { length: 1, ..none },
+            Self::Mass => BaseDimensions { mass: 1, ..none },
+            Self::Time => BaseDimensions { time: 1, ..none },
+            Self::Energy => BaseDimensions { length: 2, mass: 1, time: -2, ..none },
+            Self::Power => BaseDimensions { length: 2, mass: 1, time: -3, ..none },
+            Self::Pressure => BaseDimensions { length: -1, mass: 1, time: -2, ..none },
+            Self::DataSize => BaseDimensions { information: 1, ..none },
+        }
+    }
+}
+
+/// Returns the size of a unit in coherent SI units (meters, kilograms,
+/// seconds, kelvins, and bytes, and products of them).
+fn si_scale(unit: PrefixedUnit) -> f64 {
+    match unit.dimension() {
+        Dimension::Volume => unit.scale() / LITERS_PER_CUBIC_METER,
+        _ => unit.scale(),
+    }
+}
+
+/// Common abbreviations for compound units, in normalized form.
+const SHORTHANDS: [(&str, &str); 8] = [
+    ("mph", "mi/h"),
+    ("kph", "km/h"),
+    ("kmh", "km/h"),
+    ("fps", "ft/s"),
+    ("gpm", "gal/min"),
+    ("gph", "gal/h"),
+    ("cfm", "ft^3/min"),
+    ("lpm", "L/min"),
+];
+
+/// A product of units raised to integer powers, such as `gal/min`.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{CompoundUnit, Unit};
+///
+/// let speed: CompoundUnit = "miles per hour".parse().unwrap();
+/// assert_eq!(speed.factors(), &[(Unit::Miles.into(), 1), (Unit::Hours.into(), -1)]);
+/// assert_eq!(speed.to_string(), "mi/h");
+///
+/// let meters_per_second: CompoundUnit = "m/s".parse().unwrap();
+/// let result = speed.convert(&meters_per_second, 60.0).unwrap();
+/// assert!((result - 26.8224).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompoundUnit {
+    factors: Vec<(PrefixedUnit, i32)>,
+}
+
+impl CompoundUnit {
+    /// Creates a compound unit from units and their exponents.
+    ///
+    /// Repeated units are combined and units whose exponents cancel are
+    /// dropped, so `m·m/m` is simply `m`.
+    pub fn new(factors: impl IntoIterator<Item = (PrefixedUnit, i32)>) -> Self {
+        let mut combined: Vec<(PrefixedUnit, i32)> = Vec::new();
+        for (unit, exp) in factors {
+            match combined.iter_mut().find(|(existing, _)| *existing == unit) {
+                Some((_, total)) => *total += exp,
+                None => combined.push((unit, exp)),
+            }
+        }
+        combined.retain(|(_, exp)| *exp != 0);
+        Self { factors: combined }
+    }
+
+    /// Parses a compound unit such as `gal/min`, resolving bare volume names
+    /// such as "gallon" in the given system, like [`Unit::from_str_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CompoundUnitError`] if the text is malformed or names an
+    /// unknown unit.
+    pub fn from_str_with(s: &str, system: VolumeSystem) -> Result<Self, CompoundUnitError> {
+        let text = s
+            .split_whitespace()
+            .map(|word| if word.eq_ignore_ascii_case("per") { "/" } else { word })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let normalized = normalize(&text);
+        if let Some((_, expanded)) = SHORTHANDS.iter().find(|(short, _)| *short == normalized) {
+            return Self::from_str_with(expanded, system);
+        }
+        if let Ok(unit) = PrefixedUnit::from_str_with(&text, system) {
+            return Ok(unit.into());
+        }
+
+        let mut parser = Parser { tokens: tokenize(&text), pos: 0, system };
+        let factors = parser.quotient().and_then(|factors| match parser.tokens.get(parser.pos) {
+            None => Ok(factors),
+            Some(_) => Err(CompoundUnitError::Syntax(String::new())),
+        });
+        factors.map(Self::new).map_err(|err| match err {
+            CompoundUnitError::Syntax(_) => CompoundUnitError::Syntax(s.to_string()),
+            err => err,
+        })
+    }
+
+    /// Returns the units and exponents, in the order first written.
+    #[must_use]
+    pub fn factors(&self) -> &[(PrefixedUnit, i32)] {
+        &self.factors
+    }
+
+    /// Returns the simple unit this compound unit consists of, if it is a
+    /// single unit to the first power.
+    #[must_use]
+    pub fn unit(&self) -> Option<PrefixedUnit> {
+        match self.factors.as_slice() {
+            [(unit, 1)] => Some(*unit),
+            _ => None,
+        }
+    }
+
+    /// Returns the base quantities this unit is built from.
+    #[must_use]
+    pub fn dimensions(&self) -> BaseDimensions {
+        self.factors
+            .iter()
+            .fold(BaseDimensions::default(), |total, (unit, exp)| {
+                total.times(unit.dimension().base_dimensions().pow(*exp))
+            })
+    }
+
+    /// Returns the size of one of this unit in coherent SI units, e.g.
+    /// 0.44704 for `mi/h` (meters per second).
+    #[must_use]
+    pub fn scale(&self) -> f64 {
+        self.factors.iter().map(|(unit, exp)| si_scale(*unit).powi(*exp)).product()
+    }
+
+    /// Converts a value in this unit into another compound unit.
+    ///
+    /// Conversions between two simple units give exactly the result of
+    /// [`PrefixedUnit::convert`], and so of [`convert`](crate::convert) for
+    /// unprefixed units.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompoundUnitError::DimensionMismatch`] if the units are
+    /// built from different base quantities.
+    pub fn convert(&self, to: &CompoundUnit, value: f64) -> Result<f64, CompoundUnitError> {
+        let (from_dimensions, to_dimensions) = (self.dimensions(), to.dimensions());
+        if from_dimensions != to_dimensions {
+            return Err(CompoundUnitError::DimensionMismatch { from: from_dimensions, to: to_dimensions });
+        }
+        if let Some(result) = self.unit().zip(to.unit()).and_then(|(from, to)| from.convert(to, value)) {
+            return Ok(result);
+        }
+        Ok(value * self.scale() / to.scale())
+    }
+}
+
+impl From<Unit> for CompoundUnit {
+    fn from(unit: Unit) -> Self {
+        Self { factors: vec![(unit.into(), 1)] }
+    }
+}
+
+impl From<PrefixedUnit> for CompoundUnit {
+    fn from(unit: PrefixedUnit) -> Self {
+        Self { factors: vec![(unit, 1)] }
+    }
+}
+
+impl fmt::Display for CompoundUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbols: Vec<(String, i32)> = self.factors.iter().map(|(unit, exp)| (unit.symbol(), *exp)).collect();
+        let numerator: Vec<(&str, i32)> =
+            symbols.iter().filter(|(_, exp)| *exp > 0).map(|(symbol, exp)| (symbol.as_str(), *exp)).collect();
+        let denominator: Vec<(&str, i32)> =
+            symbols.iter().filter(|(_, exp)| *exp < 0).map(|(symbol, exp)| (symbol.as_str(), -exp)).collect();
+        write_quotient(f, &numerator, &denominator)
+    }
+}
+
+impl FromStr for CompoundUnit {
+    type Err = CompoundUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with(s, VolumeSystem::UsCustomary)
+    }
+}
+
+/// A piece of a compound unit: an operator, a parenthesis, or the text of a
+/// factor between them.
+#[derive(Debug, Clone, PartialEq)]
+enum Token<'a> {
+    Open,
+    Close,
+    Divide,
+    Multiply,
+    Text(&'a str),
+}
+
+/// Splits a compound unit into tokens, dropping blank text between them.
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        let token = match c {
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '/' => Token::Divide,
+            '*' | '·' | '⋅' => Token::Multiply,
+            _ => continue,
+        };
+        let before = text[start..i].trim();
+        if !before.is_empty() {
+            tokens.push(Token::Text(before));
+        }
+        tokens.push(token);
+        start = i + c.len_utf8();
+    }
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest));
+    }
+    tokens
+}
+
+/// A recursive-descent parser over compound-unit tokens.
+///
+/// A quotient is products separated by `/`, with every product after the
+/// first in the denominator; a product is operands separated by `*`; and an
+/// operand is a factor or a parenthesized quotient, optionally raised to a
+/// power as in `(m/s)^2`.
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+    system: VolumeSystem,
+}
+
+impl Parser<'_> {
+    fn quotient(&mut self) -> Result<Vec<(PrefixedUnit, i32)>, CompoundUnitError> {
+        let mut factors = self.product()?;
+        while self.tokens.get(self.pos) == Some(&Token::Divide) {
+            self.pos += 1;
+            factors.extend(self.product()?.into_iter().map(|(unit, exp)| (unit, -exp)));
+        }
+        Ok(factors)
+    }
+
+    fn product(&mut self) -> Result<Vec<(PrefixedUnit, i32)>, CompoundUnitError> {
+        let mut factors = self.operand()?;
+        while self.tokens.get(self.pos) == Some(&Token::Multiply) {
+            self.pos += 1;
+            factors.extend(self.operand()?);
+        }
+        Ok(factors)
+    }
+
+    fn operand(&mut self) -> Result<Vec<(PrefixedUnit, i32)>, CompoundUnitError> {
+        let syntax = || CompoundUnitError::Syntax(String::new());
+        let token = self.tokens.get(self.pos).cloned().ok_or_else(syntax)?;
+        self.pos += 1;
+        match token {
+            // A numerator of 1, as in "1/s"
+            Token::Text("1") if self.tokens.get(self.pos) == Some(&Token::Divide) => Ok(Vec::new()),
+            Token::Text(factor) => parse_factor(factor, self.system).map(|factor| vec![factor]),
+            Token::Open => {
+                let factors = self.quotient()?;
+                if self.tokens.get(self.pos) != Some(&Token::Close) {
+                    return Err(syntax());
+                }
+                self.pos += 1;
+                let Some(Token::Text(power)) = self.tokens.get(self.pos).cloned() else {
+                    return Ok(factors);
+                };
+                if !power.starts_with(['^', '²', '³']) {
+                    return Ok(factors);
+                }
+                self.pos += 1;
+                let exp = parse_exponent(power).ok_or_else(|| CompoundUnitError::InvalidExponent(power.to_string()))?;
+                Ok(factors.into_iter().map(|(unit, power)| (unit, power * exp)).collect())
+            }
+            _ => Err(syntax()),
+        }
+    }
+}
+
+/// Parses the power after a parenthesized group, such as `^2` or `²`.
+fn parse_exponent(power: &str) -> Option<i32> {
+    let power = power.replace('²', "^2").replace('³', "^3");
+    power.strip_prefix('^')?.trim().parse::<i32>().ok().filter(|exp| *exp != 0)
+}
+
+/// Parses one factor of a compound unit, such as `s²` or `in^2`.
+fn parse_factor(factor: &str, system: VolumeSystem) -> Result<(PrefixedUnit, i32), CompoundUnitError> {
+    if factor.is_empty() {
+        return Err(CompoundUnitError::Syntax(factor.to_string()));
+    }
+    let factor = factor.replace('²', "^2").replace('³', "^3");
+    let err = match PrefixedUnit::from_str_with(&factor, system) {
+        Ok(unit) => return Ok((unit, 1)),
+        Err(err) => err,
+    };
+    let Some((base, exp)) = factor.rsplit_once('^') else {
+        return Err(err.into());
+    };
+    let exp = match exp.trim().parse::<i32>() {
+        Ok(exp) if exp != 0 => exp,
+        _ => return Err(CompoundUnitError::InvalidExponent(factor)),
+    };
+    let base = base.trim();
+    let unit = PrefixedUnit::from_str_with(base, system)?;
+    Ok((unit, exp))
+}
+
+/// Writes `a·b²/(c·d)`, or `1/c` when there is no numerator.
+fn write_quotient(f: &mut fmt::Formatter<'_>, numerator: &[(&str, i32)], denominator: &[(&str, i32)]) -> fmt::Result {
+    let product = |terms: &[(&str, i32)]| {
+        terms.iter().map(|(name, exp)| format!("{name}{}", superscript(*exp))).collect::<Vec<_>>().join("·")
+    };
+    if numerator.is_empty() {
+        f.write_str("1")?;
+    } else {
+        f.write_str(&product(numerator))?;
+    }
+    match denominator {
+        [] => Ok(()),
+        [_] => write!(f, "/{}", product(denominator)),
+        _ => write!(f, "/({})", product(denominator)),
+    }
+}
+
+/// Formats an exponent as a suffix: nothing for 1, `²` and `³`, else `^n`.
+fn superscript(exp: i32) -> String {
+    match exp {
+        1 => String::new(),
+        2 => "²".to_string(),
+        3 => "³".to_string(),
+        exp => format!("^{exp}"),
+    }
+}
+
+/// Error type for parsing and converting compound units.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompoundUnitError {
+    /// A factor does not name a known unit.
+    UnknownUnit(String),
+    /// An SI prefix was applied to a unit that is not metric, as in `kgal/min`.
+    PrefixNotAllowed {
+        /// The prefix that was given.
+        prefix: Prefix,
+        /// The non-metric unit it was applied to.
+        unit: Unit,
+    },
+    /// A factor writes a byte symbol with a lower-case "b", as in `Mb/s`.
+    AmbiguousDataSize {
+        /// The text of the factor.
+        text: String,
+        /// The byte unit the factor would otherwise name.
+        unit: Unit,
+    },
+    /// A factor's exponent is not a non-zero integer.
+    InvalidExponent(String),
+    /// The text is not a product or quotient of units, e.g. `gal//min`.
+    Syntax(String),
+    /// The units are built from different base quantities.
+    DimensionMismatch {
+        /// Dimensions of the unit converted from
+        from: BaseDimensions,
+        /// Dimensions of the unit converted to
+        to: BaseDimensions,
+    },
+}
+
+impl fmt::Display for CompoundUnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownUnit(unit) => write!(f, "Unknown unit: '{unit}'"),
+            Self::PrefixNotAllowed { prefix, unit } => UnitParseError::PrefixNotAllowed { prefix: *prefix, unit: *unit }.fmt(f),
+            Self::AmbiguousDataSize { text, unit } => {
+                UnitParseError::AmbiguousDataSize { text: text.clone(), unit: *unit }.fmt(f)
+            }
+            Self::InvalidExponent(factor) => write!(f, "Invalid exponent in '{factor}'"),
+            Self::Syntax(text) => write!(f, "Invalid compound unit: '{text}'"),
+            Self::DimensionMismatch { from, to } => write!(f, "Cannot convert {from} to {to}"),
+        }
+    }
+}
+
+impl std::error::Error for CompoundUnitError {}
+
+impl From<UnitParseError> for CompoundUnitError {
+    fn from(err: UnitParseError) -> Self {
+        match err {
+            UnitParseError::UnknownUnit(unit) => Self::UnknownUnit(unit),
+            UnitParseError::PrefixNotAllowed { prefix, unit } => Self::PrefixNotAllowed { prefix, unit },
+            // Factors are parsed as prefixed units, so this only comes from a
+            // plain `Unit` parse
+            UnitParseError::Prefixed { prefix, unit } => Self::UnknownUnit(format!("{}{}", prefix.symbol(), unit.symbol())),
+            UnitParseError::AmbiguousDataSize { text, unit } => Self::AmbiguousDataSize { text, unit },
+        }
+    }
+}
+
+/// Parses two compound units and converts a value between them.
+///
+/// # Errors
+///
+/// Returns a [`CompoundUnitError`] if either unit cannot be parsed or the
+/// units are built from different base quantities.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::convert_compound;
+///
+/// // Water is 1 g/mL, or about 62.43 lb/ft³
+/// let density = convert_compound("g/mL", "lb/ft³", 1.0).unwrap();
+/// assert!((density - 62.428).abs() < 0.001);
+///
+/// assert!(convert_compound("mph", "gal/min", 1.0).is_err());
+/// ```
+pub fn convert_compound(from: &str, to: &str, value: f64) -> Result<f64, CompoundUnitError> {
+    from.parse::<CompoundUnit>()?.convert(&to.parse()?, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+    use crate::{TOLERANCE_EPSILON, TOLERANCE_ULPS};
+
+    fn parse(s: &str) -> CompoundUnit {
+        s.parse().unwrap_or_else(|err| panic!("{s}: {err}"))
+    }
+
+    #[test]
+    // test parsing quotients, products, exponents, and "per"
+    fn test_parse_compound() {
+        assert_eq!(parse("gal/min").factors(), &[(Unit::Gallons.into(), 1), (Unit::Minutes.into(), -1)]);
+        assert_eq!(parse("gallons per minute"), parse("gal/min"));
+        assert_eq!(parse("lb/ft³").factors(), &[(Unit::Pounds.into(), 1), (Unit::CubicFeet.into(), -1)]);
+        assert_eq!(
+            parse("kg/(m*s^2)").factors(),
+            &[(Unit::Kilograms.into(), 1), (Unit::Meters.into(), -1), (Unit::Seconds.into(), -2)]
+        );
+        assert_eq!(parse("kg/m/s²"), parse("kg/(m·s^2)"));
+        assert_eq!(parse("1/s").factors(), &[(Unit::Seconds.into(), -1)]);
+        assert_eq!(parse("mph"), parse("mi/h"));
+        assert_eq!(parse("m*m/m").unit(), Some(Unit::Meters.into()));
+        assert_eq!(parse("cubic feet").unit(), Some(Unit::CubicFeet.into()));
+        let imperial = CompoundUnit::from_str_with("gpm", VolumeSystem::Imperial).unwrap();
+        assert_eq!(imperial.factors(), &[(Unit::ImperialGallons.into(), 1), (Unit::Minutes.into(), -1)]);
+    }
+
+    #[test]
+    // test parse errors
+    fn test_parse_compound_errors() {
+        assert_eq!("furlongs/s".parse::<CompoundUnit>(), Err(CompoundUnitError::UnknownUnit("furlongs".to_string())));
+        assert_eq!("m^x/s".parse::<CompoundUnit>(), Err(CompoundUnitError::InvalidExponent("m^x".to_string())));
+        assert_eq!("gal//min".parse::<CompoundUnit>(), Err(CompoundUnitError::Syntax("gal//min".to_string())));
+        assert_eq!(
+            "kgal/min".parse::<CompoundUnit>(),
+            Err(CompoundUnitError::PrefixNotAllowed { prefix: Prefix::Kilo, unit: Unit::Gallons })
+        );
+        for text in ["kg/(m/s", "kg/m)", "(m)s", "()", "kg/(m·)", "1"] {
+            assert!(text.parse::<CompoundUnit>().is_err(), "{text}");
+        }
+        assert_eq!("kg/(m/s".parse::<CompoundUnit>(), Err(CompoundUnitError::Syntax("kg/(m/s".to_string())));
+        assert_eq!("(m/s)^x".parse::<CompoundUnit>(), Err(CompoundUnitError::InvalidExponent("^x".to_string())));
+    }
+
+    #[test]
+    // test nested parentheses and powers of groups
+    fn test_parse_compound_nested() {
+        assert_eq!(
+            parse("kg/(m/s)").factors(),
+            &[(Unit::Kilograms.into(), 1), (Unit::Meters.into(), -1), (Unit::Seconds.into(), 1)]
+        );
+        assert_eq!(parse("kg/(m·(s^2))"), parse("kg/(m·s²)"));
+        assert_eq!(parse("(m/s)^2").factors(), &[(Unit::Meters.into(), 2), (Unit::Seconds.into(), -2)]);
+        assert_eq!(
+            parse("kg·(m/s)²").factors(),
+            &[(Unit::Kilograms.into(), 1), (Unit::Meters.into(), 2), (Unit::Seconds.into(), -2)]
+        );
+        assert_eq!(parse("((gal))/((min))"), parse("gal/min"));
+    }
+
+    #[test]
+    // test display uses symbols and superscripts
+    fn test_display_compound() {
+        assert_eq!(parse("gallons per minute").to_string(), "gal/min");
+        assert_eq!(parse("kg/(m*s^2)").to_string(), "kg/(m·s²)");
+        assert_eq!(parse("1/s").to_string(), "1/s");
+        assert_eq!(parse("m/s^4").to_string(), "m/s^4");
+    }
+
+    #[test]
+    // test speed, density, flow rate, and pressure conversions
+    fn test_convert_compound() {
+        let cases = [
+            ("mph", "m/s", 60.0, 26.8224),
+            ("km/h", "mph", 100.0, 62.1371),
+            ("g/mL", "lb/ft^3", 1.0, 62.428),
+            ("kg/m³", "g/cm^3", 1000.0, 1.0),
+            ("gal/min", "L/s", 1.0, 0.0631),
+            ("ft³/min", "gal/h", 1.0, 448.8312),
+            ("kg/(m*s^2)", "g/(cm*s^2)", 1.0, 10.0),
+            ("acres/h", "m^2/s", 1.0, 1.1241),
+        ];
+        for (from, to, value, expected) in cases {
+            let result = convert_compound(from, to, value).unwrap();
+            assert!(approx_eq!(f64, result, expected, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{from} -> {to}: {result}");
+        }
+    }
+
+    #[test]
+    // test simple units convert exactly as convert does, including offsets
+    fn test_convert_compound_simple_units() {
+        assert_eq!(convert_compound("celsius", "kelvin", 70.0), Ok(343.15));
+        assert_eq!(convert_compound("liters", "gallons", 1.0), Ok(0.2641));
+        let liters = convert_compound("m^3", "L", 2.0).unwrap();
+        assert!(approx_eq!(f64, liters, 2000.0, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+    }
+
+    #[test]
+    // test energy, power, and pressure units combine with other units
+    fn test_convert_compound_derived_dimensions() {
+        let cases = [
+            ("kW*h", "kWh", 1.0, 1.0),
+            ("J/s", "W", 5.0, 5.0),
+            ("kg*m^2/s^2", "J", 1.0, 1.0),
+            ("psi", "kg/(m*s^2)", 1.0, 6894.7573),
+            ("BTU/h", "W", 3412.1416, 1000.0),
+            ("MB/s", "MiB/min", 1.0, 57.2205),
+            ("µm/ms", "m/s", 1.0, 0.001),
+            ("mL/min", "kL/h", 1000.0, 0.06),
+            ("hPa", "psi", 1013.25, 14.6959),
+        ];
+        for (from, to, value, expected) in cases {
+            let result = convert_compound(from, to, value).unwrap();
+            assert!(approx_eq!(f64, result, expected, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{from} -> {to}: {result}");
+        }
+    }
+
+    #[test]
+    // test converting between different base quantities fails
+    fn test_convert_compound_mismatch() {
+        let err = convert_compound("gal/min", "lb/ft^3", 1.0).unwrap_err();
+        assert_eq!(err.to_string(), "Cannot convert length³/time to mass/length³");
+        assert!(convert_compound("m/s", "m/s^2", 1.0).is_err());
+    }
+}
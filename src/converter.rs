@@ -0,0 +1,245 @@
+//! Conversions resolved once and applied many times.
+//!
+//! A [`Converter`] captures the combined scale and offset of a conversion
+//! between two units, so applying it is a single multiply-add with no
+//! matching on units. Converters can be inverted and chained, and are built
+//! by `const fn`s so fixed pairs can be resolved at compile time:
+//!
+//! ```rust
+//! use rustoleum::{Converter, Unit};
+//!
+//! const FAHRENHEIT_TO_KELVIN: Converter = match Converter::new(Unit::Fahrenheit, Unit::Kelvin) {
+//!     Ok(converter) => converter,
+//!     Err(_) => panic!("temperature units always convert"),
+//! };
+//!
+//! assert!((FAHRENHEIT_TO_KELVIN.apply(212.0) - 373.15).abs() < 1e-9);
+//! ```
+
+use core::fmt;
+
+use crate::{volume_factor, Unit};
+
+/// Error returned when two units cannot be converted or chained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncompatibleUnits {
+    /// The unit converted from
+    pub from: Unit,
+    /// The unit converted to
+    pub to: Unit,
+}
+
+impl fmt::Display for IncompatibleUnits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Cannot convert {} to {}", self.from, self.to)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IncompatibleUnits {}
+
+/// A precomputed conversion `y = x × scale + offset` between two units.
+///
+/// Results agree with [`convert`](crate::convert) to within floating-point
+/// rounding; conversions that are a single multiplication, such as the
+/// original volume table, agree exactly.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{Converter, Unit};
+///
+/// let to_gallons = Converter::new(Unit::Liters, Unit::Gallons).unwrap();
+/// assert_eq!(to_gallons.apply(1.0), 0.2641);
+///
+/// let to_quarts = to_gallons.then(Converter::new(Unit::Gallons, Unit::Quarts).unwrap()).unwrap();
+/// assert!((to_quarts.apply(1.0) - 1.0564).abs() < 1e-9);
+/// assert!((to_quarts.inverse().apply(1.0564) - 1.0).abs() < 1e-9);
+///
+/// assert!(Converter::new(Unit::Liters, Unit::Kelvin).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Converter {
+    from: Unit,
+    to: Unit,
+    scale: f64,
+    offset: f64,
+}
+
+impl Converter {
+    /// Resolves the conversion from one unit to another.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncompatibleUnits`] if the units measure different
+    /// dimensions.
+    pub const fn new(from: Unit, to: Unit) -> Result<Self, IncompatibleUnits> {
+        if from as u8 == to as u8 {
+            return Ok(Self { from, to, scale: 1.0, offset: 0.0 });
+        }
+        if from.dimension() as u8 != to.dimension() as u8 {
+            return Err(IncompatibleUnits { from, to });
+        }
+        // The original volume pairs keep their published factors
+        if let Some(factor) = volume_factor(from, to) {
+            return Ok(Self { from, to, scale: factor, offset: 0.0 });
+        }
+        Ok(Self {
+            from,
+            to,
+            scale: from.scale() / to.scale(),
+            offset: (from.offset() - to.offset()) / to.scale(),
+        })
+    }
+
+    /// Returns the unit converted from.
+    #[must_use]
+    pub const fn from(self) -> Unit {
+        self.from
+    }
+
+    /// Returns the unit converted to.
+    #[must_use]
+    pub const fn to(self) -> Unit {
+        self.to
+    }
+
+    /// Returns the factor each value is multiplied by.
+    #[must_use]
+    pub const fn scale(self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the amount added after scaling (non-zero only between
+    /// temperature scales with different zero points).
+    #[must_use]
+    pub const fn offset(self) -> f64 {
+        self.offset
+    }
+
+    /// Converts a value.
+    #[inline]
+    #[must_use]
+    pub const fn apply(self, value: f64) -> f64 {
+        value * self.scale + self.offset
+    }
+
+    /// Returns the conversion in the opposite direction.
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        Self {
+            from: self.to,
+            to: self.from,
+            scale: 1.0 / self.scale,
+            offset: -self.offset / self.scale,
+        }
+    }
+
+    /// Chains this conversion with one that starts where it ends, e.g.
+    /// °F → °C then °C → K gives °F → K.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncompatibleUnits`] with this converter's target and the
+    /// next converter's source if they are not the same unit.
+    pub const fn then(self, next: Self) -> Result<Self, IncompatibleUnits> {
+        if self.to as u8 != next.from as u8 {
+            return Err(IncompatibleUnits { from: self.to, to: next.from });
+        }
+        Ok(Self {
+            from: self.from,
+            to: next.to,
+            scale: self.scale * next.scale,
+            offset: self.offset * next.scale + next.offset,
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for Converter {
+    /// Formats the conversion as a formula, e.g. "K = °F × 0.5556 + 255.3722".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use crate::explain::number;
+
+        write!(f, "{} = {} × {}", self.to.symbol(), self.from.symbol(), number(self.scale))?;
+        if self.offset > 0.0 {
+            write!(f, " + {}", number(self.offset))?;
+        } else if self.offset < 0.0 {
+            write!(f, " - {}", number(-self.offset))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{convert, Dimension};
+    use float_cmp::approx_eq;
+
+    /// Asserts two values agree to within a relative 1e-9.
+    fn assert_close(result: f64, expected: f64, context: &str) {
+        assert!(
+            approx_eq!(f64, result, expected, epsilon = 1e-9 * expected.abs().max(1.0), ulps = 4),
+            "{context}: {result} != {expected}"
+        );
+    }
+
+    #[test]
+    // test converters agree with convert for every pair
+    fn test_converter_matches_convert() {
+        for &from in Unit::all() {
+            for &to in Unit::all() {
+                let converter = Converter::new(from, to);
+                assert_eq!(converter.is_ok(), convert(from, to, 0.0).is_some(), "{from} -> {to}");
+                let Ok(converter) = converter else { continue };
+                for value in [-40.0, 0.0, 1.0, 70.0, 1234.5] {
+                    assert_close(converter.apply(value), convert(from, to, value).unwrap(), &format!("{value} {from} -> {to}"));
+                }
+            }
+        }
+        // The original volume table agrees exactly
+        let converter = Converter::new(Unit::Cups, Unit::CubicInches).unwrap();
+        assert_eq!(Some(converter.apply(3.0)), convert(Unit::Cups, Unit::CubicInches, 3.0));
+    }
+
+    #[test]
+    // test inverses and chains
+    fn test_converter_inverse_and_then() {
+        let to_celsius = Converter::new(Unit::Fahrenheit, Unit::Celsius).unwrap();
+        let to_kelvin = Converter::new(Unit::Celsius, Unit::Kelvin).unwrap();
+        let chained = to_celsius.then(to_kelvin).unwrap();
+        assert_eq!((chained.from(), chained.to()), (Unit::Fahrenheit, Unit::Kelvin));
+        assert_close(chained.apply(212.0), 373.15, "°F -> °C -> K");
+
+        let back = chained.inverse();
+        assert_eq!((back.from(), back.to()), (Unit::Kelvin, Unit::Fahrenheit));
+        assert_close(back.apply(373.15), 212.0, "K -> °F");
+
+        assert_eq!(
+            to_kelvin.then(to_celsius),
+            Err(IncompatibleUnits { from: Unit::Kelvin, to: Unit::Fahrenheit })
+        );
+        for &dimension in Dimension::all() {
+            for unit in dimension.units() {
+                let converter = Converter::new(unit, dimension.base_unit()).unwrap();
+                let round_trip = converter.then(converter.inverse()).unwrap();
+                assert_close(round_trip.apply(42.0), 42.0, &format!("{unit} round trip"));
+            }
+        }
+    }
+
+    #[test]
+    // test converters can be built in const contexts
+    fn test_converter_const() {
+        const RANKINE: Converter = match Converter::new(Unit::Celsius, Unit::Rankine) {
+            Ok(converter) => converter,
+            Err(_) => panic!("temperature units convert"),
+        };
+        const BOILING: f64 = RANKINE.apply(100.0);
+        assert_close(BOILING, 671.67, "°C -> °R");
+        const MISMATCH: Result<Converter, IncompatibleUnits> = Converter::new(Unit::Liters, Unit::Kelvin);
+        assert!(MISMATCH.is_err());
+        assert_eq!(RANKINE.to_string(), "°R = °C × 1.8 + 491.67");
+    }
+}
@@ -0,0 +1,583 @@
+//! Step-by-step explanations of unit conversions.
+//!
+//! An [`Explanation`] breaks a conversion into the same arithmetic steps the
+//! conversion functions perform, recording the intermediate value after each
+//! step so a student can see where a wrong answer went astray.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{
+    volume_factor, Substance, Unit, ABSOLUTE_ZERO_CELSIUS, CELSIUS_TO_RANKINE_OFFSET, FAHRENHEIT_FREEZING,
+    FAHRENHEIT_TO_RANKINE_OFFSET,
+};
+
+/// Number of decimal places shown for values in rendered explanations.
+const DISPLAY_PRECISION: usize = 4;
+
+/// Number of significant digits always shown, so that small factors such
+/// as 0.000001 are not rounded away.
+const SIGNIFICANT_DIGITS: i32 = 4;
+
+/// A single arithmetic operation applied during a conversion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operation {
+    /// Add a constant to the running value.
+    Add(f64),
+    /// Subtract a constant from the running value.
+    Subtract(f64),
+    /// Multiply the running value by `numerator / denominator`.
+    ///
+    /// Factors such as 5/9 are kept as fractions so they can be shown the way
+    /// they are taught; plain decimal factors use a denominator of `1.0`.
+    Multiply {
+        /// Numerator of the factor
+        numerator: f64,
+        /// Denominator of the factor
+        denominator: f64,
+    },
+    /// Divide the running value by a constant.
+    Divide(f64),
+}
+
+impl Operation {
+    /// Multiplies by a fraction.
+    const fn fraction(numerator: f64, denominator: f64) -> Self {
+        Self::Multiply { numerator, denominator }
+    }
+
+    /// Multiplies by a plain decimal factor.
+    const fn factor(factor: f64) -> Self {
+        Self::Multiply { numerator: factor, denominator: 1.0 }
+    }
+
+    /// Applies the operation to a value.
+    #[must_use]
+    pub fn apply(&self, value: f64) -> f64 {
+        match *self {
+            Self::Add(n) => value + n,
+            Self::Subtract(n) => value - n,
+            Self::Multiply { numerator, denominator } => value * (numerator / denominator),
+            Self::Divide(n) => value / n,
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Add(n) => write!(f, "Add {}", number(n)),
+            Self::Subtract(n) => write!(f, "Subtract {}", number(n)),
+            Self::Multiply { numerator, denominator } => {
+                write!(f, "Multiply by {}", factor_text(numerator, denominator))
+            }
+            Self::Divide(n) => write!(f, "Divide by {}", number(n)),
+        }
+    }
+}
+
+/// One step of an [`Explanation`], with the values before and after it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Step {
+    /// The operation performed in this step
+    pub operation: Operation,
+    /// The running value before the operation
+    pub input: f64,
+    /// The running value after the operation
+    pub output: f64,
+}
+
+/// Output format for rendering an [`Explanation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Format {
+    /// Plain text, suitable for a terminal
+    #[default]
+    Text,
+    /// Markdown, suitable for worksheets and answer keys
+    Markdown,
+    /// LaTeX `align*` environment
+    Latex,
+}
+
+/// Error type for parsing an explanation [`Format`] from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatParseError(pub String);
+
+impl fmt::Display for FormatParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown explanation format: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for FormatParseError {}
+
+impl FromStr for Format {
+    type Err = FormatParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "TEXT" | "PLAIN" => Ok(Self::Text),
+            "MARKDOWN" | "MD" => Ok(Self::Markdown),
+            "LATEX" | "TEX" => Ok(Self::Latex),
+            _ => Err(FormatParseError(s.to_string())),
+        }
+    }
+}
+
+/// A conversion broken down into human-readable steps.
+///
+/// Created by [`explain`]. The final step's output is the same value that
+/// [`convert`](crate::convert) returns for the same arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    from: Unit,
+    to: Unit,
+    input: f64,
+    steps: Vec<Step>,
+    substance: Option<Substance>,
+}
+
+impl Explanation {
+    /// Returns the unit being converted from.
+    #[must_use]
+    pub fn from(&self) -> Unit {
+        self.from
+    }
+
+    /// Returns the unit being converted to.
+    #[must_use]
+    pub fn to(&self) -> Unit {
+        self.to
+    }
+
+    /// Returns the value being converted.
+    #[must_use]
+    pub fn input(&self) -> f64 {
+        self.input
+    }
+
+    /// Returns the substance whose density the conversion uses, if any.
+    #[must_use]
+    pub fn substance(&self) -> Option<Substance> {
+        self.substance
+    }
+
+    /// Returns the steps of the conversion, in order.
+    #[must_use]
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Returns the converted value.
+    #[must_use]
+    pub fn result(&self) -> f64 {
+        self.steps.last().map_or(self.input, |step| step.output)
+    }
+
+    /// Records the substance being converted, as in "2 cups of flour".
+    pub(crate) fn with_substance(self, substance: Substance) -> Self {
+        Self { substance: Some(substance), ..self }
+    }
+
+    /// Returns the unit being converted from, with the substance if any,
+    /// e.g. "cups of all-purpose flour", singular for an input of 1.
+    fn source_label(&self) -> String {
+        let from = self.from.name(self.input != 1.0);
+        match self.substance {
+            Some(substance) => format!("{from} of {substance}"),
+            None => from.to_string(),
+        }
+    }
+
+    /// Returns the unit converted to, singular for a result of 1.
+    fn result_label(&self) -> &'static str {
+        self.to.name(number(self.result()) != "1")
+    }
+
+    /// Returns the density line for a volume ↔ mass conversion, if any.
+    fn density_label(&self) -> Option<String> {
+        let substance = self.substance?;
+        (self.from.dimension() != self.to.dimension()).then(|| {
+            format!("Density of {substance} ({} g/mL)", number(substance.density()))
+        })
+    }
+
+    /// Renders the explanation in the requested format.
+    #[must_use]
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.to_text(),
+            Format::Markdown => self.to_markdown(),
+            Format::Latex => self.to_latex(),
+        }
+    }
+
+    /// Renders the explanation as plain text.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut out = format!(
+            "Convert {} {} to {}:\n",
+            number(self.input),
+            self.source_label(),
+            self.to
+        );
+        if let Some(density) = self.density_label() {
+            out.push_str(&format!("  {density}\n"));
+        }
+        if self.steps.is_empty() {
+            out.push_str("  (same unit, no conversion needed)\n");
+        }
+        for (i, step) in self.steps.iter().enumerate() {
+            out.push_str(&format!(
+                "  {}. {}: {} = {}\n",
+                i + 1,
+                step.operation,
+                plain_expression(step),
+                number(step.output)
+            ));
+        }
+        out.push_str(&format!("Result: {} {}", number(self.result()), self.result_label()));
+        out
+    }
+
+    /// Renders the explanation as a Markdown numbered list.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "**Convert {} {} to {}**\n\n",
+            number(self.input),
+            self.source_label(),
+            self.to
+        );
+        if let Some(density) = self.density_label() {
+            out.push_str(&format!("_{density}_\n\n"));
+        }
+        if self.steps.is_empty() {
+            out.push_str("_Same unit, no conversion needed._\n");
+        }
+        for (i, step) in self.steps.iter().enumerate() {
+            out.push_str(&format!(
+                "{}. {}: `{} = {}`\n",
+                i + 1,
+                step.operation,
+                plain_expression(step),
+                number(step.output)
+            ));
+        }
+        out.push_str(&format!(
+            "\n**Result:** {} {}",
+            number(self.result()),
+            self.result_label()
+        ));
+        out
+    }
+
+    /// Renders the explanation as a LaTeX `align*` environment.
+    #[must_use]
+    pub fn to_latex(&self) -> String {
+        let mut out = String::from("\\begin{align*}\n");
+        if self.steps.is_empty() {
+            out.push_str(&format!(
+                "{}\\ \\text{{{}}} &= {}\\ \\text{{{}}}\n",
+                number(self.input),
+                self.source_label(),
+                number(self.result()),
+                self.result_label()
+            ));
+        }
+        for (i, step) in self.steps.iter().enumerate() {
+            let input = if i == 0 {
+                format!("{}\\ \\text{{{}}}", number(step.input), self.source_label())
+            } else {
+                number(step.input)
+            };
+            let operation = match step.operation {
+                Operation::Add(n) => format!("{input} + {}", number(n)),
+                Operation::Subtract(n) => format!("{input} - {}", number(n)),
+                Operation::Multiply { numerator, denominator: 1.0 } => {
+                    format!("{input} \\times {}", number(numerator))
+                }
+                Operation::Multiply { numerator, denominator } => format!(
+                    "{input} \\times \\frac{{{}}}{{{}}}",
+                    number(numerator),
+                    number(denominator)
+                ),
+                Operation::Divide(n) => format!("\\frac{{{input}}}{{{}}}", number(n)),
+            };
+            let last = i + 1 == self.steps.len();
+            let output = if last {
+                format!("{}\\ \\text{{{}}}", number(step.output), self.result_label())
+            } else {
+                format!("{} \\\\", number(step.output))
+            };
+            out.push_str(&format!("{operation} &= {output}\n"));
+        }
+        out.push_str("\\end{align*}");
+        out
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_text())
+    }
+}
+
+/// Explains how a value is converted from one unit to another.
+///
+/// Returns the conversion as a sequence of [`Step`]s whose final output
+/// matches [`convert`](crate::convert), or `None` if the conversion is
+/// invalid (e.g., temperature to volume).
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{explain, Unit};
+///
+/// let explanation = explain(Unit::Fahrenheit, Unit::Kelvin, 70.0).unwrap();
+/// assert_eq!(explanation.steps().len(), 3);
+/// println!("{}", explanation.to_markdown());
+/// ```
+#[must_use]
+pub fn explain(from: Unit, to: Unit, value: f64) -> Option<Explanation> {
+    let operations = operations(from, to)?;
+    Some(explain_operations(from, to, value, &operations))
+}
+
+/// Builds an explanation by applying each operation to the running value.
+pub(crate) fn explain_operations(from: Unit, to: Unit, value: f64, operations: &[Operation]) -> Explanation {
+    let mut running = value;
+    let steps = operations
+        .iter()
+        .map(|&operation| {
+            let input = running;
+            running = operation.apply(input);
+            Step { operation, input, output: running }
+        })
+        .collect();
+    Explanation { from, to, input: value, steps, substance: None }
+}
+
+/// Returns the operations performed by the conversion function for a pair.
+///
+/// These mirror the formulas in the conversion functions one-for-one so the
+/// explained result is identical to the converted one.
+fn operations(from: Unit, to: Unit) -> Option<Vec<Operation>> {
+    use Operation::{Add, Subtract};
+
+    let to_fahrenheit = Operation::fraction(9.0, 5.0);
+    let to_celsius = Operation::fraction(5.0, 9.0);
+
+    if from == to {
+        return Some(Vec::new());
+    }
+
+    let operations = match (from, to) {
+        // Temperature conversions
+        (Unit::Kelvin, Unit::Celsius) => vec![Subtract(ABSOLUTE_ZERO_CELSIUS)],
+        (Unit::Kelvin, Unit::Fahrenheit) => vec![
+            Subtract(ABSOLUTE_ZERO_CELSIUS),
+            to_fahrenheit,
+            Add(FAHRENHEIT_FREEZING),
+        ],
+        (Unit::Kelvin, Unit::Rankine) => vec![to_fahrenheit],
+        (Unit::Celsius, Unit::Kelvin) => vec![Add(ABSOLUTE_ZERO_CELSIUS)],
+        (Unit::Celsius, Unit::Fahrenheit) => vec![to_fahrenheit, Add(FAHRENHEIT_FREEZING)],
+        (Unit::Celsius, Unit::Rankine) => vec![to_fahrenheit, Add(CELSIUS_TO_RANKINE_OFFSET)],
+        (Unit::Fahrenheit, Unit::Kelvin) => vec![
+            Subtract(FAHRENHEIT_FREEZING),
+            to_celsius,
+            Add(ABSOLUTE_ZERO_CELSIUS),
+        ],
+        (Unit::Fahrenheit, Unit::Celsius) => vec![Subtract(FAHRENHEIT_FREEZING), to_celsius],
+        (Unit::Fahrenheit, Unit::Rankine) => vec![Add(FAHRENHEIT_TO_RANKINE_OFFSET)],
+        (Unit::Rankine, Unit::Kelvin) => vec![to_celsius],
+        (Unit::Rankine, Unit::Celsius) => vec![to_celsius, Subtract(ABSOLUTE_ZERO_CELSIUS)],
+        (Unit::Rankine, Unit::Fahrenheit) => vec![Subtract(FAHRENHEIT_TO_RANKINE_OFFSET)],
+        _ if from.dimension() != to.dimension() => return None,
+        // Volume conversions, then any other pair through the base unit
+        _ => match volume_factor(from, to) {
+            Some(factor) => vec![Operation::factor(factor)],
+            None => base_operations(from, to),
+        },
+    };
+    Some(operations)
+}
+
+/// Returns the steps of [`crate::via_base`]: a single factor between units
+/// without offsets, otherwise scale into the base unit, shift between the
+/// two zero points, and scale out of the base unit. Steps that would leave
+/// the value unchanged are omitted.
+fn base_operations(from: Unit, to: Unit) -> Vec<Operation> {
+    if from.offset() == 0.0 && to.offset() == 0.0 {
+        return vec![Operation::factor(from.scale() / to.scale())];
+    }
+    let mut operations = Vec::new();
+    if from.scale() != 1.0 {
+        operations.push(Operation::factor(from.scale()));
+    }
+    let shift = from.offset() - to.offset();
+    if shift > 0.0 {
+        operations.push(Operation::Add(shift));
+    } else if shift < 0.0 {
+        operations.push(Operation::Subtract(-shift));
+    }
+    if to.scale() != 1.0 {
+        operations.push(Operation::Divide(to.scale()));
+    }
+    operations
+}
+
+/// Formats a step as `input op operand`, e.g. `70 - 32`.
+fn plain_expression(step: &Step) -> String {
+    let input = number(step.input);
+    match step.operation {
+        Operation::Add(n) => format!("{input} + {}", number(n)),
+        Operation::Subtract(n) => format!("{input} - {}", number(n)),
+        Operation::Multiply { numerator, denominator } => {
+            format!("{input} × {}", factor_text(numerator, denominator))
+        }
+        Operation::Divide(n) => format!("{input} ÷ {}", number(n)),
+    }
+}
+
+/// Formats a multiplication factor, as a fraction when it has a denominator.
+fn factor_text(numerator: f64, denominator: f64) -> String {
+    if denominator == 1.0 {
+        number(numerator)
+    } else {
+        format!("{}/{}", number(numerator), number(denominator))
+    }
+}
+
+/// Formats a value to [`DISPLAY_PRECISION`] decimal places, or more when
+/// needed to show [`SIGNIFICANT_DIGITS`] significant digits, trimming
+/// trailing zeros.
+pub(crate) fn number(value: f64) -> String {
+    let magnitude = if value.is_normal() { value.abs().log10().floor() as i32 } else { 0 };
+    let decimals = (SIGNIFICANT_DIGITS - 1 - magnitude).max(DISPLAY_PRECISION as i32) as usize;
+    let text = format!("{value:.decimals$}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert;
+
+    #[test]
+    // test every explanation ends on exactly the value convert returns
+    fn test_explain_matches_convert() {
+        for &from in Unit::all() {
+            for &to in Unit::all() {
+                for value in [-40.0, 0.0, 1.0, 70.0, 123.456] {
+                    let explained = explain(from, to, value).map(|e| e.result());
+                    assert_eq!(explained, convert(from, to, value), "{from:?} -> {to:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    // test Fahrenheit to Kelvin steps and intermediate values
+    fn test_explain_fah_kel_steps() {
+        let explanation = explain(Unit::Fahrenheit, Unit::Kelvin, 70.0).unwrap();
+        let steps = explanation.steps();
+        assert_eq!(steps.len(), 3);
+        assert_eq!(steps[0].operation, Operation::Subtract(32.0));
+        assert_eq!(steps[0].output, 38.0);
+        assert_eq!(steps[1].operation, Operation::fraction(5.0, 9.0));
+        assert_eq!(steps[2].operation, Operation::Add(273.15));
+    }
+
+    #[test]
+    // test pairs outside the worksheet table are explained through the base unit
+    fn test_explain_via_base() {
+        let explanation = explain(Unit::Celsius, Unit::Reaumur, 100.0).unwrap();
+        assert_eq!(explanation.steps().len(), 1);
+        assert_eq!(explanation.steps()[0].operation, Operation::Divide(1.25));
+        assert_eq!(explanation.result(), 80.0);
+        assert_eq!(
+            explanation.to_text(),
+            "Convert 100 degrees Celsius to degrees Réaumur:\n\
+             \x20 1. Divide by 1.25: 100 ÷ 1.25 = 80\n\
+             Result: 80 degrees Réaumur"
+        );
+    }
+
+    #[test]
+    // test small factors keep their digits and metric pairs use one factor
+    fn test_explain_metric_factor() {
+        assert_eq!(
+            explain(Unit::Milligrams, Unit::Grams, 500.0).unwrap().to_text(),
+            "Convert 500 milligrams to grams:\n\
+             \x20 1. Multiply by 0.001: 500 × 0.001 = 0.5\n\
+             Result: 0.5 grams"
+        );
+        assert_eq!(
+            explain(Unit::Kilograms, Unit::Grams, 1.0).unwrap().to_text(),
+            "Convert 1 kilogram to grams:\n\
+             \x20 1. Multiply by 1000: 1 × 1000 = 1000\n\
+             Result: 1000 grams"
+        );
+        assert_eq!(number(0.000001), "0.000001");
+        assert_eq!(number(0.00012346), "0.0001235");
+        assert_eq!(number(294.26111), "294.2611");
+    }
+
+    #[test]
+    // test plain-text rendering
+    fn test_explain_to_text() {
+        let text = explain(Unit::Fahrenheit, Unit::Kelvin, 70.0).unwrap().to_text();
+        let expected = "Convert 70 degrees Fahrenheit to kelvins:\n\
+                        \x20 1. Subtract 32: 70 - 32 = 38\n\
+                        \x20 2. Multiply by 5/9: 38 × 5/9 = 21.1111\n\
+                        \x20 3. Add 273.15: 21.1111 + 273.15 = 294.2611\n\
+                        Result: 294.2611 kelvins";
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    // test Markdown rendering
+    fn test_explain_to_markdown() {
+        let markdown = explain(Unit::Liters, Unit::Gallons, 2.0).unwrap().to_markdown();
+        let expected = "**Convert 2 liters to gallons**\n\n\
+                        1. Multiply by 0.2641: `2 × 0.2641 = 0.5282`\n\
+                        \n**Result:** 0.5282 gallons";
+        assert_eq!(markdown, expected);
+    }
+
+    #[test]
+    // test LaTeX rendering
+    fn test_explain_to_latex() {
+        let latex = explain(Unit::Celsius, Unit::Fahrenheit, 100.0).unwrap().to_latex();
+        let expected = "\\begin{align*}\n\
+                        100\\ \\text{degrees Celsius} \\times \\frac{9}{5} &= 180 \\\\\n\
+                        180 + 32 &= 212\\ \\text{degrees Fahrenheit}\n\
+                        \\end{align*}";
+        assert_eq!(latex, expected);
+    }
+
+    #[test]
+    // test invalid and same-unit explanations
+    fn test_explain_invalid_and_same_unit() {
+        assert!(explain(Unit::Celsius, Unit::Liters, 1.0).is_none());
+        let same = explain(Unit::Cups, Unit::Cups, 3.0).unwrap();
+        assert!(same.steps().is_empty());
+        assert_eq!(same.result(), 3.0);
+    }
+
+    #[test]
+    // test Format parsing
+    fn test_format_from_str() {
+        assert_eq!(Format::from_str("markdown"), Ok(Format::Markdown));
+        assert_eq!(Format::from_str("LaTeX"), Ok(Format::Latex));
+        assert_eq!(Format::from_str("text"), Ok(Format::Text));
+        assert!(Format::from_str("html").is_err());
+    }
+}
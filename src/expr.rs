@@ -0,0 +1,619 @@
+//! A small expression language over quantities with units.
+//!
+//! Expressions combine numbers and quantities with `+ - * /` and
+//! parentheses, and may end with an `in <unit>` or `to <unit>` clause naming
+//! the unit of the result:
+//!
+//! ```text
+//! 3 gallons + 2 cups in liters
+//! (1 cubic-feet - 500 cubic-inches) / 2 to gallons
+//! ```
+//!
+//! Addition and subtraction convert the right operand into the unit of the
+//! left one, so both must have the same dimension. Quantities can be scaled
+//! by plain numbers, and dividing two quantities of the same dimension gives
+//! a plain number.
+//!
+//! Units are read as [`PrefixedUnit`] reads them, so `2 kL` is 2000 liters.
+//! A prefixed quantity is converted to its unprefixed unit, and the target
+//! unit must be a built-in unit.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::explain::number;
+use crate::{convert, Dimension, PrefixedUnit, Unit, VolumeSystem};
+
+/// A number with an optional unit, produced by [`evaluate`].
+///
+/// With the `serde` feature, quantities serialize as
+/// `{"value": 3.0, "unit": "gallons"}`, with a `null` unit for plain numbers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quantity {
+    /// The numeric value
+    pub value: f64,
+    /// The unit of the value, or `None` for a plain number
+    pub unit: Option<Unit>,
+}
+
+impl Quantity {
+    /// Creates a quantity with a unit.
+    #[must_use]
+    pub const fn new(value: f64, unit: Unit) -> Self {
+        Self { value, unit: Some(unit) }
+    }
+
+    /// Creates a plain number with no unit.
+    #[must_use]
+    pub const fn number(value: f64) -> Self {
+        Self { value, unit: None }
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.unit {
+            Some(unit) => write!(f, "{} {}", number(self.value), unit),
+            None => f.write_str(&number(self.value)),
+        }
+    }
+}
+
+/// Error type for parsing and evaluating expressions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprError {
+    /// A character that cannot start any token, at the given byte offset.
+    UnexpectedCharacter(char, usize),
+    /// A token that does not fit the grammar at this point.
+    UnexpectedToken(String),
+    /// The expression ended where more input was expected.
+    UnexpectedEnd,
+    /// A word that does not name any known unit.
+    UnknownUnit(String),
+    /// The operands have incompatible dimensions (`None` is a plain number).
+    DimensionMismatch {
+        /// Unit of the left operand
+        left: Option<Unit>,
+        /// Unit of the right operand
+        right: Option<Unit>,
+    },
+    /// Temperatures on different scales cannot be added or subtracted, and
+    /// temperatures cannot be scaled, because the scales have offsets.
+    TemperatureArithmetic(Unit),
+    /// The operation would need a compound unit such as gallons × liters.
+    UnsupportedOperation(&'static str),
+    /// Division by zero.
+    DivisionByZero,
+}
+
+impl fmt::Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedCharacter(c, at) => write!(f, "Unexpected character '{c}' at position {at}"),
+            Self::UnexpectedToken(token) => write!(f, "Unexpected '{token}'"),
+            Self::UnexpectedEnd => write!(f, "Unexpected end of expression"),
+            Self::UnknownUnit(unit) => write!(f, "Unknown unit: '{unit}'"),
+            Self::DimensionMismatch { left, right } => write!(
+                f,
+                "Dimension mismatch: {} and {}",
+                dimension_label(*left),
+                dimension_label(*right)
+            ),
+            Self::TemperatureArithmetic(unit) => {
+                write!(f, "Cannot combine {} with that operation", unit)
+            }
+            Self::UnsupportedOperation(operation) => write!(f, "Unsupported operation: {operation}"),
+            Self::DivisionByZero => write!(f, "Division by zero"),
+        }
+    }
+}
+
+impl std::error::Error for ExprError {}
+
+/// Evaluates an expression such as `"3 gallons + 2 cups in liters"`.
+///
+/// Without an `in`/`to` clause the result is expressed in the unit of the
+/// left-most quantity.
+///
+/// # Errors
+///
+/// Returns an [`ExprError`] if the expression cannot be parsed, names an
+/// unknown unit, or combines quantities with incompatible dimensions.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{evaluate, Unit};
+///
+/// let result = evaluate("1 gallons + 16 cups to gallons").unwrap();
+/// assert_eq!(result.unit, Some(Unit::Gallons));
+/// assert_eq!(result.value, 2.0);
+///
+/// assert!(evaluate("1 gallons + 3 kelvin").is_err());
+/// ```
+pub fn evaluate(input: &str) -> Result<Quantity, ExprError> {
+    evaluate_with(input, VolumeSystem::UsCustomary)
+}
+
+/// Evaluates an expression like [`evaluate`], resolving bare volume names
+/// such as "gallon" in the given system, like [`Unit::from_str_with`].
+///
+/// # Errors
+///
+/// Returns an [`ExprError`] as [`evaluate`] does.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{evaluate_with, Unit, VolumeSystem};
+///
+/// let result = evaluate_with("2 pints in US pints", VolumeSystem::Imperial).unwrap();
+/// assert_eq!(result.unit, Some(Unit::Pints));
+/// assert!((result.value - 2.4019).abs() < 1e-4);
+/// ```
+pub fn evaluate_with(input: &str, system: VolumeSystem) -> Result<Quantity, ExprError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser { tokens, pos: 0, system };
+    let result = parser.expression()?;
+
+    let result = match parser.next() {
+        None => result,
+        Some(Token::Word(word)) if is_target_keyword(&word) => {
+            let target = parser.unit()?.ok_or(ExprError::UnexpectedEnd)?;
+            let target = target
+                .builtin()
+                .ok_or(ExprError::UnsupportedOperation("converting to an SI-prefixed unit"))?;
+            convert_quantity(result, target)?
+        }
+        Some(token) => return Err(ExprError::UnexpectedToken(token.to_string())),
+    };
+
+    match parser.next() {
+        None => Ok(result),
+        Some(token) => Err(ExprError::UnexpectedToken(token.to_string())),
+    }
+}
+
+impl FromStr for Quantity {
+    type Err = ExprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        evaluate(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Word(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    LeftParen,
+    RightParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Word(word) => f.write_str(word),
+            Self::Plus => f.write_str("+"),
+            Self::Minus => f.write_str("-"),
+            Self::Star => f.write_str("*"),
+            Self::Slash => f.write_str("/"),
+            Self::LeftParen => f.write_str("("),
+            Self::RightParen => f.write_str(")"),
+        }
+    }
+}
+
+/// Returns true for the words that introduce the target unit clause.
+fn is_target_keyword(word: &str) -> bool {
+    word.eq_ignore_ascii_case("in") || word.eq_ignore_ascii_case("to")
+}
+
+/// Returns true for characters that can start a unit word.
+fn is_word_start(c: char) -> bool {
+    c.is_alphabetic() || matches!(c, '°' | 'µ' | '_')
+}
+
+/// Returns true for characters that can continue a unit word.
+fn is_word_char(c: char) -> bool {
+    is_word_start(c) || c.is_ascii_digit() || matches!(c, '^' | '²' | '³' | '.')
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ExprError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '+' => {
+                chars.next();
+                tokens.push(Token::Plus);
+            }
+            '-' | '−' => {
+                chars.next();
+                tokens.push(Token::Minus);
+            }
+            '*' | '×' | '·' => {
+                chars.next();
+                tokens.push(Token::Star);
+            }
+            '/' | '÷' => {
+                chars.next();
+                tokens.push(Token::Slash);
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LeftParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RightParen);
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let mut end = start;
+                let mut seen_exponent = false;
+                while let Some(&(i, c)) = chars.peek() {
+                    let rest = &input[i..];
+                    let exponent = !seen_exponent
+                        && matches!(c, 'e' | 'E')
+                        && rest[1..].trim_start_matches(['+', '-']).starts_with(|d: char| d.is_ascii_digit());
+                    if c.is_ascii_digit() || c == '.' {
+                        end = i + 1;
+                        chars.next();
+                    } else if exponent {
+                        seen_exponent = true;
+                        chars.next();
+                        if let Some(&(j, sign @ ('+' | '-'))) = chars.peek() {
+                            end = j + sign.len_utf8();
+                            chars.next();
+                        }
+                    } else {
+                        break;
+                    }
+                }
+                let text = &input[start..end];
+                let value = text
+                    .parse::<f64>()
+                    .map_err(|_| ExprError::UnexpectedToken(text.to_string()))?;
+                tokens.push(Token::Number(value));
+            }
+            c if is_word_start(c) => {
+                let mut end = start;
+                while let Some(&(i, c)) = chars.peek() {
+                    // A hyphen joins words like "cubic-feet" but is a minus
+                    // sign anywhere else.
+                    let joins_words =
+                        c == '-' && end > start && input[i + 1..].starts_with(is_word_start);
+                    if is_word_char(c) || joins_words {
+                        end = i + c.len_utf8();
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Word(input[start..end].to_string()));
+            }
+            c => return Err(ExprError::UnexpectedCharacter(c, start)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    system: VolumeSystem,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    /// expression := term (("+" | "-") term)*
+    fn expression(&mut self) -> Result<Quantity, ExprError> {
+        let mut left = self.term()?;
+        loop {
+            match self.peek() {
+                Some(Token::Plus) => {
+                    self.pos += 1;
+                    let right = self.term()?;
+                    left = add(left, right, 1.0)?;
+                }
+                Some(Token::Minus) => {
+                    self.pos += 1;
+                    let right = self.term()?;
+                    left = add(left, right, -1.0)?;
+                }
+                _ => return Ok(left),
+            }
+        }
+    }
+
+    /// term := factor (("*" | "/") factor)*
+    fn term(&mut self) -> Result<Quantity, ExprError> {
+        let mut left = self.factor()?;
+        loop {
+            match self.peek() {
+                Some(Token::Star) => {
+                    self.pos += 1;
+                    let right = self.factor()?;
+                    left = multiply(left, right)?;
+                }
+                Some(Token::Slash) => {
+                    self.pos += 1;
+                    let right = self.factor()?;
+                    left = divide(left, right)?;
+                }
+                _ => return Ok(left),
+            }
+        }
+    }
+
+    /// factor := "-" factor | "(" expression ")" | number [unit]
+    fn factor(&mut self) -> Result<Quantity, ExprError> {
+        match self.next() {
+            Some(Token::Minus) => {
+                let inner = self.factor()?;
+                Ok(Quantity { value: -inner.value, ..inner })
+            }
+            Some(Token::LeftParen) => {
+                let inner = self.expression()?;
+                match self.next() {
+                    Some(Token::RightParen) => Ok(inner),
+                    Some(token) => Err(ExprError::UnexpectedToken(token.to_string())),
+                    None => Err(ExprError::UnexpectedEnd),
+                }
+            }
+            Some(Token::Number(value)) => match self.unit()? {
+                Some(unit) => match unit.builtin() {
+                    Some(builtin) => Ok(Quantity::new(value, builtin)),
+                    None => {
+                        let base = unit.unit();
+                        Ok(Quantity::new(unit.convert(base.into(), value).expect("same unit"), base))
+                    }
+                },
+                None => Ok(Quantity::number(value)),
+            },
+            Some(token) => Err(ExprError::UnexpectedToken(token.to_string())),
+            None => Err(ExprError::UnexpectedEnd),
+        }
+    }
+
+    /// Parses the longest run of words that names a unit.
+    ///
+    /// Unit names may contain spaces, and "in" is also the target keyword, so
+    /// the run is shortened from the right until it parses; leftover words are
+    /// left for the caller (usually an `in`/`to` clause). A leading "in"
+    /// followed by a unit name is the target keyword, not inches, unless
+    /// another keyword and unit follow it, as in "1 in in cm".
+    fn unit(&mut self) -> Result<Option<PrefixedUnit>, ExprError> {
+        let words: Vec<&str> = self.tokens[self.pos.min(self.tokens.len())..]
+            .iter()
+            .map_while(|token| match token {
+                Token::Word(word) => Some(word.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        let target_follows = |words: &[&str]| {
+            words.first().is_some_and(|word| is_target_keyword(word))
+                && (2..=words.len()).any(|len| PrefixedUnit::from_str_with(&words[1..len].join(" "), self.system).is_ok())
+        };
+        if target_follows(&words) && !target_follows(&words[1..]) {
+            return Ok(None);
+        }
+
+        for len in (1..=words.len()).rev() {
+            if let Ok(unit) = PrefixedUnit::from_str_with(&words[..len].join(" "), self.system) {
+                self.pos += len;
+                return Ok(Some(unit));
+            }
+        }
+
+        match words.iter().position(|word| is_target_keyword(word)) {
+            Some(0) => Ok(None),
+            Some(keyword) => Err(ExprError::UnknownUnit(words[..keyword].join(" "))),
+            None if words.is_empty() => Ok(None),
+            None => Err(ExprError::UnknownUnit(words.join(" "))),
+        }
+    }
+}
+
+/// Returns true for units on a temperature scale.
+fn is_temperature(unit: Unit) -> bool {
+    unit.dimension() == Dimension::Temperature
+}
+
+/// Converts a quantity into the target unit.
+fn convert_quantity(quantity: Quantity, target: Unit) -> Result<Quantity, ExprError> {
+    let Some(unit) = quantity.unit else {
+        return Err(ExprError::DimensionMismatch { left: None, right: Some(target) });
+    };
+    convert(unit, target, quantity.value)
+        .map(|value| Quantity::new(value, target))
+        .ok_or(ExprError::DimensionMismatch { left: Some(unit), right: Some(target) })
+}
+
+/// Adds `sign * right` to `left`, in the unit of `left`.
+fn add(left: Quantity, right: Quantity, sign: f64) -> Result<Quantity, ExprError> {
+    match (left.unit, right.unit) {
+        (None, None) => Ok(Quantity::number(left.value + sign * right.value)),
+        (Some(a), Some(b)) if is_temperature(a) && is_temperature(b) && a != b => {
+            Err(ExprError::TemperatureArithmetic(b))
+        }
+        (Some(a), Some(_)) => {
+            let right = convert_quantity(right, a).map_err(|_| ExprError::DimensionMismatch {
+                left: left.unit,
+                right: right.unit,
+            })?;
+            Ok(Quantity::new(left.value + sign * right.value, a))
+        }
+        (a, b) => Err(ExprError::DimensionMismatch { left: a, right: b }),
+    }
+}
+
+fn multiply(left: Quantity, right: Quantity) -> Result<Quantity, ExprError> {
+    match (left.unit, right.unit) {
+        (Some(unit), _) | (_, Some(unit)) if is_temperature(unit) => {
+            Err(ExprError::TemperatureArithmetic(unit))
+        }
+        (Some(_), Some(_)) => Err(ExprError::UnsupportedOperation("multiplying two quantities")),
+        (unit, None) | (None, unit) => Ok(Quantity { value: left.value * right.value, unit }),
+    }
+}
+
+fn divide(left: Quantity, right: Quantity) -> Result<Quantity, ExprError> {
+    if right.value == 0.0 {
+        return Err(ExprError::DivisionByZero);
+    }
+    match (left.unit, right.unit) {
+        (Some(unit), _) | (_, Some(unit)) if is_temperature(unit) => {
+            Err(ExprError::TemperatureArithmetic(unit))
+        }
+        (unit, None) => Ok(Quantity { value: left.value / right.value, unit }),
+        (Some(a), Some(_)) => {
+            let right = convert_quantity(right, a).map_err(|_| ExprError::DimensionMismatch {
+                left: left.unit,
+                right: right.unit,
+            })?;
+            Ok(Quantity::number(left.value / right.value))
+        }
+        (None, Some(_)) => Err(ExprError::UnsupportedOperation("dividing a number by a quantity")),
+    }
+}
+
+/// Describes the dimension of an operand for error messages.
+fn dimension_label(unit: Option<Unit>) -> String {
+    match unit {
+        None => "a plain number".to_string(),
+        Some(unit) => format!("{} ({unit})", unit.dimension()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+    use crate::{TOLERANCE_EPSILON, TOLERANCE_ULPS};
+
+    #[test]
+    // test adding volumes with a target unit
+    fn test_evaluate_sum_in_target() {
+        let result = evaluate("3 gallons + 2 cups in liters").unwrap();
+        assert_eq!(result.unit, Some(Unit::Liters));
+        let expected = 3.0 * 3.785 + 2.0 * 0.236_588;
+        assert!(approx_eq!(f64, result.value, expected, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+    }
+
+    #[test]
+    // test result defaults to the left-most unit
+    fn test_evaluate_default_unit() {
+        let result = evaluate("1 gallons - 8 cups").unwrap();
+        assert_eq!(result, Quantity::new(0.5, Unit::Gallons));
+    }
+
+    #[test]
+    // test precedence, parentheses, and scaling
+    fn test_evaluate_precedence() {
+        assert_eq!(evaluate("2 + 3 * 4").unwrap(), Quantity::number(14.0));
+        assert_eq!(evaluate("(2 + 3) * 4").unwrap(), Quantity::number(20.0));
+        assert_eq!(evaluate("-(1 cups + 1 cups) * 2").unwrap(), Quantity::new(-4.0, Unit::Cups));
+        assert_eq!(evaluate("1 gallons / 1 cups").unwrap(), Quantity::number(16.0));
+        assert_eq!(evaluate("1.5e1 tablespoons to tablespoons").unwrap().value, 15.0);
+    }
+
+    #[test]
+    // test multi-word and hyphenated unit names next to the target keyword
+    fn test_evaluate_unit_words() {
+        let result = evaluate("1 cubic feet / 2 in gallons").unwrap();
+        assert_eq!(result.unit, Some(Unit::Gallons));
+        let result = evaluate("12 in + 1 ft to cm").unwrap();
+        assert!(approx_eq!(f64, result.value, 60.96, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+
+        let result = evaluate("1728 cubic-inches to cubic-feet").unwrap();
+        assert_eq!(result.unit, Some(Unit::CubicFeet));
+        assert!(approx_eq!(f64, result.value, 1.0, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+        let result = evaluate("100 celsius in fahrenheit").unwrap();
+        assert_eq!(result, Quantity::new(212.0, Unit::Fahrenheit));
+        let result = evaluate("231 cu in to gal").unwrap();
+        assert_eq!(result.unit, Some(Unit::Gallons));
+        assert_eq!(evaluate("2 gal in cups").unwrap(), Quantity::new(32.0, Unit::Cups));
+    }
+
+    #[test]
+    // test "in" as inches right before the target keyword
+    fn test_evaluate_inches_before_keyword() {
+        assert_eq!(evaluate("1 in in cm").unwrap(), Quantity::new(2.54, Unit::Centimeters));
+        assert_eq!(evaluate("2 in to cm").unwrap(), Quantity::new(5.08, Unit::Centimeters));
+        assert_eq!(evaluate("2.54 cm in in").unwrap(), Quantity::new(1.0, Unit::Inches));
+        assert!(matches!(evaluate("2 in cm"), Err(ExprError::DimensionMismatch { left: None, .. })));
+    }
+
+    #[test]
+    // test dimension mismatches are reported as errors
+    fn test_evaluate_dimension_mismatch() {
+        assert_eq!(
+            evaluate("3 gallons + 2 kelvin"),
+            Err(ExprError::DimensionMismatch { left: Some(Unit::Gallons), right: Some(Unit::Kelvin) })
+        );
+        assert_eq!(
+            evaluate("3 gallons + 2"),
+            Err(ExprError::DimensionMismatch { left: Some(Unit::Gallons), right: None })
+        );
+        assert_eq!(
+            evaluate("3 liters in celsius"),
+            Err(ExprError::DimensionMismatch { left: Some(Unit::Liters), right: Some(Unit::Celsius) })
+        );
+    }
+
+    #[test]
+    // test temperature offsets are not silently mixed
+    fn test_evaluate_temperature_arithmetic() {
+        assert_eq!(evaluate("10 celsius + 5 celsius").unwrap(), Quantity::new(15.0, Unit::Celsius));
+        assert_eq!(
+            evaluate("10 celsius + 5 fahrenheit"),
+            Err(ExprError::TemperatureArithmetic(Unit::Fahrenheit))
+        );
+        assert_eq!(evaluate("2 * 10 kelvin"), Err(ExprError::TemperatureArithmetic(Unit::Kelvin)));
+    }
+
+    #[test]
+    // test syntax errors
+    fn test_evaluate_syntax_errors() {
+        assert_eq!(evaluate("3 furlongs"), Err(ExprError::UnknownUnit("furlongs".to_string())));
+        assert_eq!(evaluate("(1 + 2"), Err(ExprError::UnexpectedEnd));
+        assert_eq!(evaluate("1 + $"), Err(ExprError::UnexpectedCharacter('$', 4)));
+        assert_eq!(evaluate("1 cups / 0"), Err(ExprError::DivisionByZero));
+        assert_eq!(evaluate("1 cups in"), Err(ExprError::UnexpectedEnd));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    // test quantities serialize with their unit name
+    fn test_quantity_serde() {
+        let quantity = evaluate("3 gallons + 2 cups in gallons").unwrap();
+        let json = serde_json::to_string(&quantity).unwrap();
+        assert_eq!(json, r#"{"value":3.125,"unit":"gallons"}"#);
+        assert_eq!(serde_json::from_str::<Quantity>(&json).unwrap(), quantity);
+        assert_eq!(serde_json::to_string(&Quantity::number(2.0)).unwrap(), r#"{"value":2.0,"unit":null}"#);
+        let aliased = serde_json::from_str::<Quantity>(r#"{"value":1.5,"unit":"L"}"#).unwrap();
+        assert_eq!(aliased, Quantity::new(1.5, Unit::Liters));
+    }
+}
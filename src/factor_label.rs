@@ -0,0 +1,384 @@
+//! Dimensional-analysis ("factor-label") conversion chains.
+//!
+//! The factor-label method writes a conversion as the starting quantity
+//! multiplied by a chain of unit fractions, each equal to one, so that the
+//! units cancel down to the target:
+//!
+//! ```text
+//! 1 gal × 231 in³/1 gal × 1 ft³/1728 in³ = 0.1337 ft³
+//! ```
+//!
+//! Only multiplicative conversions (no offset, so not Celsius or Fahrenheit)
+//! can be written this way.
+
+use std::fmt;
+
+use crate::explain::number;
+use crate::{convert, Unit};
+
+/// Relative tolerance used when checking a unit fraction against the
+/// library's conversion factor.
+///
+/// Generous enough to accept factors rounded the way textbooks print them
+/// (e.g. 1 ft³/7.48 gal), but tight enough to reject wrong factors.
+pub const FACTOR_LABEL_TOLERANCE: f64 = 0.005;
+
+/// A unit fraction such as `231 in³ / 1 gal`, equal to one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitFraction {
+    /// Amount in the numerator
+    pub numerator: f64,
+    /// Unit in the numerator
+    pub numerator_unit: Unit,
+    /// Amount in the denominator
+    pub denominator: f64,
+    /// Unit in the denominator (cancelled against the running unit)
+    pub denominator_unit: Unit,
+}
+
+impl UnitFraction {
+    /// Creates a unit fraction `numerator numerator_unit / denominator denominator_unit`.
+    #[must_use]
+    pub const fn new(numerator: f64, numerator_unit: Unit, denominator: f64, denominator_unit: Unit) -> Self {
+        Self { numerator, numerator_unit, denominator, denominator_unit }
+    }
+
+    /// Returns the conventional unit fraction for converting `from` into `to`.
+    ///
+    /// The fraction is written with a whole `1` on the side of the larger
+    /// unit, so 1 gallon to cubic inches is `231 in³/1 gal` and cubic inches
+    /// to cubic feet is `1 ft³/1728 in³`. Returns `None` if the conversion is
+    /// invalid or not multiplicative.
+    #[must_use]
+    pub fn between(from: Unit, to: Unit) -> Option<Self> {
+        let forward = multiplicative_factor(from, to)?;
+        if forward >= 1.0 {
+            return Some(Self::new(forward, to, 1.0, from));
+        }
+        let reverse = multiplicative_factor(to, from)?;
+        Some(Self::new(1.0, to, reverse, from))
+    }
+
+    /// Returns the value of the fraction as a plain factor.
+    #[must_use]
+    pub fn factor(&self) -> f64 {
+        self.numerator / self.denominator
+    }
+}
+
+impl fmt::Display for UnitFraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}/{} {}",
+            number(self.numerator),
+            self.numerator_unit.symbol(),
+            number(self.denominator),
+            self.denominator_unit.symbol()
+        )
+    }
+}
+
+/// Error type for checking a factor-label chain.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FactorLabelError {
+    /// The conversion between these units has an offset and cannot be
+    /// written as unit fractions.
+    NotMultiplicative(Unit, Unit),
+    /// The fraction at `step` does not cancel the running unit.
+    UnitsDoNotCancel {
+        /// Zero-based index of the offending fraction
+        step: usize,
+        /// The running unit that should appear in the denominator
+        expected: Unit,
+        /// The unit that was found in the denominator
+        found: Unit,
+    },
+    /// The fraction at `step` does not equal one.
+    IncorrectFactor {
+        /// Zero-based index of the offending fraction
+        step: usize,
+    },
+    /// The chain ends in a unit other than the target.
+    WrongTarget {
+        /// The requested target unit
+        expected: Unit,
+        /// The unit the chain actually ends in
+        found: Unit,
+    },
+}
+
+impl fmt::Display for FactorLabelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotMultiplicative(from, to) => {
+                write!(f, "Conversion from {} to {} is not multiplicative", from.symbol(), to.symbol())
+            }
+            Self::UnitsDoNotCancel { step, expected, found } => write!(
+                f,
+                "Fraction {} has {} in the denominator but needs {} to cancel",
+                step + 1,
+                found.symbol(),
+                expected.symbol()
+            ),
+            Self::IncorrectFactor { step } => {
+                write!(f, "Fraction {} is not a correct conversion factor", step + 1)
+            }
+            Self::WrongTarget { expected, found } => {
+                write!(f, "Chain ends in {} instead of {}", found.symbol(), expected.symbol())
+            }
+        }
+    }
+}
+
+impl std::error::Error for FactorLabelError {}
+
+/// A starting quantity multiplied by a chain of unit fractions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FactorLabelChain {
+    value: f64,
+    from: Unit,
+    fractions: Vec<UnitFraction>,
+}
+
+impl FactorLabelChain {
+    /// Returns the starting value.
+    #[must_use]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the starting unit.
+    #[must_use]
+    pub fn from(&self) -> Unit {
+        self.from
+    }
+
+    /// Returns the unit the chain ends in.
+    #[must_use]
+    pub fn to(&self) -> Unit {
+        self.fractions.last().map_or(self.from, |fraction| fraction.numerator_unit)
+    }
+
+    /// Returns the unit fractions, in order.
+    #[must_use]
+    pub fn fractions(&self) -> &[UnitFraction] {
+        &self.fractions
+    }
+
+    /// Returns the value obtained by multiplying through the chain.
+    #[must_use]
+    pub fn result(&self) -> f64 {
+        self.fractions.iter().fold(self.value, |acc, fraction| acc * fraction.factor())
+    }
+
+    /// Renders the chain as a LaTeX expression using `\frac`.
+    #[must_use]
+    pub fn to_latex(&self) -> String {
+        let mut out = format!("{}\\ \\text{{{}}}", number(self.value), self.from.symbol());
+        for fraction in &self.fractions {
+            out.push_str(&format!(
+                " \\times \\frac{{{}\\ \\text{{{}}}}}{{{}\\ \\text{{{}}}}}",
+                number(fraction.numerator),
+                fraction.numerator_unit.symbol(),
+                number(fraction.denominator),
+                fraction.denominator_unit.symbol()
+            ));
+        }
+        out.push_str(&format!(" = {}\\ \\text{{{}}}", number(self.result()), self.to().symbol()));
+        out
+    }
+}
+
+impl fmt::Display for FactorLabelChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", number(self.value), self.from.symbol())?;
+        for fraction in &self.fractions {
+            write!(f, " × {fraction}")?;
+        }
+        write!(f, " = {} {}", number(self.result()), self.to().symbol())
+    }
+}
+
+/// Builds the factor-label chain for a direct conversion.
+///
+/// Returns `None` if the conversion is invalid or not multiplicative.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{factor_label, Unit};
+///
+/// let chain = factor_label(Unit::Gallons, Unit::CubicInches, 2.0).unwrap();
+/// assert_eq!(chain.to_string(), "2 gal × 231 in³/1 gal = 462 in³");
+/// ```
+#[must_use]
+pub fn factor_label(from: Unit, to: Unit, value: f64) -> Option<FactorLabelChain> {
+    factor_label_via(from, &[], to, value)
+}
+
+/// Builds the factor-label chain for a conversion through intermediate units.
+///
+/// Each hop (`from` → `via[0]` → … → `to`) contributes one unit fraction.
+/// Returns `None` if any hop is invalid or not multiplicative.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{factor_label_via, Unit};
+///
+/// let chain = factor_label_via(Unit::Gallons, &[Unit::CubicInches], Unit::CubicFeet, 1.0).unwrap();
+/// assert_eq!(chain.to_string(), "1 gal × 231 in³/1 gal × 1 ft³/1728 in³ = 0.1337 ft³");
+/// ```
+#[must_use]
+pub fn factor_label_via(from: Unit, via: &[Unit], to: Unit, value: f64) -> Option<FactorLabelChain> {
+    let mut fractions = Vec::with_capacity(via.len() + 1);
+    let mut current = from;
+    for &next in via.iter().chain(std::iter::once(&to)) {
+        if next != current {
+            fractions.push(UnitFraction::between(current, next)?);
+        }
+        current = next;
+    }
+    Some(FactorLabelChain { value, from, fractions })
+}
+
+/// Checks a student's factor-label chain for converting `value` from `from` to `to`.
+///
+/// Every fraction must cancel the running unit, equal one to within
+/// [`FACTOR_LABEL_TOLERANCE`], and the chain must end in `to`. On success the
+/// checked chain is returned so its [`result`](FactorLabelChain::result) can
+/// be graded like any other answer.
+///
+/// # Errors
+///
+/// Returns a [`FactorLabelError`] describing the first problem found.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{check_factor_label, Unit, UnitFraction};
+///
+/// let chain = check_factor_label(Unit::Gallons, Unit::CubicFeet, 1.0, &[
+///     UnitFraction::new(231.0, Unit::CubicInches, 1.0, Unit::Gallons),
+///     UnitFraction::new(1.0, Unit::CubicFeet, 1728.0, Unit::CubicInches),
+/// ]).unwrap();
+/// assert!((chain.result() - 0.1337).abs() < 0.0001);
+/// ```
+pub fn check_factor_label(
+    from: Unit,
+    to: Unit,
+    value: f64,
+    fractions: &[UnitFraction],
+) -> Result<FactorLabelChain, FactorLabelError> {
+    let mut current = from;
+    for (step, fraction) in fractions.iter().enumerate() {
+        if fraction.denominator_unit != current {
+            return Err(FactorLabelError::UnitsDoNotCancel {
+                step,
+                expected: current,
+                found: fraction.denominator_unit,
+            });
+        }
+        let (numerator_unit, denominator_unit) = (fraction.numerator_unit, fraction.denominator_unit);
+        let forward = multiplicative_factor(denominator_unit, numerator_unit)
+            .ok_or(FactorLabelError::NotMultiplicative(denominator_unit, numerator_unit))?;
+        let reverse = multiplicative_factor(numerator_unit, denominator_unit)
+            .ok_or(FactorLabelError::NotMultiplicative(numerator_unit, denominator_unit))?;
+        let factor = fraction.factor();
+        if !close(factor, forward) && !close(factor, 1.0 / reverse) {
+            return Err(FactorLabelError::IncorrectFactor { step });
+        }
+        current = numerator_unit;
+    }
+    if current != to {
+        return Err(FactorLabelError::WrongTarget { expected: to, found: current });
+    }
+    Ok(FactorLabelChain { value, from, fractions: fractions.to_vec() })
+}
+
+/// Returns the factor for a conversion with no offset, or `None` if the
+/// conversion is invalid or has an offset.
+fn multiplicative_factor(from: Unit, to: Unit) -> Option<f64> {
+    if convert(from, to, 0.0)? != 0.0 {
+        return None;
+    }
+    convert(from, to, 1.0)
+}
+
+/// Returns true if `actual` is within [`FACTOR_LABEL_TOLERANCE`] of `expected`.
+fn close(actual: f64, expected: f64) -> bool {
+    ((actual - expected) / expected).abs() <= FACTOR_LABEL_TOLERANCE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+    use crate::{TOLERANCE_EPSILON, TOLERANCE_ULPS};
+
+    #[test]
+    // test conventional fractions put the whole 1 on the larger unit
+    fn test_unit_fraction_between() {
+        let fraction = UnitFraction::between(Unit::Gallons, Unit::CubicInches).unwrap();
+        assert_eq!(fraction, UnitFraction::new(231.0, Unit::CubicInches, 1.0, Unit::Gallons));
+        let fraction = UnitFraction::between(Unit::CubicInches, Unit::CubicFeet).unwrap();
+        assert_eq!(fraction, UnitFraction::new(1.0, Unit::CubicFeet, 1728.0, Unit::CubicInches));
+    }
+
+    #[test]
+    // test chain through an intermediate unit agrees with direct conversion
+    fn test_factor_label_via() {
+        let chain = factor_label_via(Unit::Gallons, &[Unit::CubicInches], Unit::CubicFeet, 1.0).unwrap();
+        assert_eq!(chain.fractions().len(), 2);
+        assert_eq!(chain.to(), Unit::CubicFeet);
+        let direct = convert(Unit::Gallons, Unit::CubicFeet, 1.0).unwrap();
+        assert!(approx_eq!(f64, chain.result(), direct, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+    }
+
+    #[test]
+    // test Kelvin to Rankine is multiplicative but Celsius to Kelvin is not
+    fn test_factor_label_offsets() {
+        assert!(factor_label(Unit::Kelvin, Unit::Rankine, 100.0).is_some());
+        assert!(factor_label(Unit::Celsius, Unit::Kelvin, 100.0).is_none());
+        assert!(factor_label(Unit::Liters, Unit::Kelvin, 1.0).is_none());
+    }
+
+    #[test]
+    // test LaTeX rendering of a chain
+    fn test_factor_label_to_latex() {
+        let chain = factor_label(Unit::Cups, Unit::Tablespoons, 2.0).unwrap();
+        assert_eq!(
+            chain.to_latex(),
+            "2\\ \\text{cup} \\times \\frac{16\\ \\text{tbsp}}{1\\ \\text{cup}} = 32\\ \\text{tbsp}"
+        );
+    }
+
+    #[test]
+    // test checking a correct and incorrect student chain
+    fn test_check_factor_label() {
+        let good = [
+            UnitFraction::new(4.226, Unit::Cups, 1.0, Unit::Liters),
+            UnitFraction::new(1.0, Unit::Gallons, 16.0, Unit::Cups),
+        ];
+        assert!(check_factor_label(Unit::Liters, Unit::Gallons, 2.0, &good).is_ok());
+
+        let upside_down = [UnitFraction::new(1.0, Unit::Liters, 4.226, Unit::Cups)];
+        assert_eq!(
+            check_factor_label(Unit::Liters, Unit::Cups, 2.0, &upside_down),
+            Err(FactorLabelError::UnitsDoNotCancel { step: 0, expected: Unit::Liters, found: Unit::Cups })
+        );
+
+        let wrong_factor = [UnitFraction::new(12.0, Unit::Tablespoons, 1.0, Unit::Cups)];
+        assert_eq!(
+            check_factor_label(Unit::Cups, Unit::Tablespoons, 1.0, &wrong_factor),
+            Err(FactorLabelError::IncorrectFactor { step: 0 })
+        );
+
+        let short = [UnitFraction::new(231.0, Unit::CubicInches, 1.0, Unit::Gallons)];
+        assert_eq!(
+            check_factor_label(Unit::Gallons, Unit::CubicFeet, 1.0, &short),
+            Err(FactorLabelError::WrongTarget { expected: Unit::CubicFeet, found: Unit::CubicInches })
+        );
+    }
+}
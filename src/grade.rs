@@ -0,0 +1,135 @@
+//! Grading a student's answer against the expected conversion.
+//!
+//! An answer is correct when it matches the expected value after both are
+//! rounded to the tenths place, half away from zero. Rounding is done in
+//! decimal arithmetic: binary floating point cannot represent most tenths
+//! and hundredths, so an answer of 2.45 is really 2.4500000000000002 as an
+//! `f64` and 0.35 is 0.34999999999999998, and rounding those directly can
+//! land on the wrong side of the midpoint.
+
+use core::fmt;
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::Number;
+
+/// The outcome of grading an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "lowercase"))]
+pub enum Grade {
+    /// The answer matches the expected value to the tenths place.
+    Correct,
+    /// The answer does not match, or either value is not finite.
+    Incorrect,
+}
+
+impl fmt::Display for Grade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Correct => "correct",
+            Self::Incorrect => "incorrect",
+        })
+    }
+}
+
+/// Rounds a value to the tenths place, half away from zero.
+///
+/// Floating-point values are first read as the shortest decimal that
+/// round-trips to them, so `0.35_f64` rounds to 0.4. Returns `None` if the
+/// value is NaN or infinite.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{round_tenths, Decimal};
+///
+/// assert_eq!(round_tenths(0.35), Some(Decimal::new(4, 1)));
+/// assert_eq!(round_tenths(-2.25_f32), Some(Decimal::new(-23, 1)));
+/// assert_eq!(round_tenths(f64::NAN), None);
+/// ```
+#[must_use]
+pub fn round_tenths<T: Number>(value: T) -> Option<Decimal> {
+    let value = value.to_decimal()?;
+    Some(value.round_dp_with_strategy(1, RoundingStrategy::MidpointAwayFromZero))
+}
+
+/// Grades an answer against the expected value by rounding both to the
+/// tenths place.
+///
+/// The two values may be different [`Number`] types, so an expected `f64`
+/// can be checked against an answer parsed exactly as a [`Decimal`].
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{convert, grade, Decimal, Grade, Unit};
+///
+/// let expected = convert(Unit::Liters, Unit::Gallons, 3.0).unwrap(); // 0.7923
+/// assert_eq!(grade(expected, 0.8), Grade::Correct);
+/// assert_eq!(grade(expected, "0.74".parse::<Decimal>().unwrap()), Grade::Incorrect);
+/// ```
+#[must_use]
+pub fn grade<E: Number, A: Number>(expected: E, answer: A) -> Grade {
+    match (round_tenths(expected), round_tenths(answer)) {
+        (Some(expected), Some(answer)) if expected == answer => Grade::Correct,
+        _ => Grade::Incorrect,
+    }
+}
+
+/// Reads an answer exactly as written, in plain or scientific notation, so
+/// that it rounds to the tenths place the way it would on paper.
+///
+/// Returns `None` if the text is not a number.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{parse_answer, Decimal};
+///
+/// assert_eq!(parse_answer("2.45"), Some(Decimal::new(245, 2)));
+/// assert_eq!(parse_answer("1.5e2"), Some(Decimal::new(150, 0)));
+/// assert_eq!(parse_answer("abc"), None);
+/// ```
+#[must_use]
+pub fn parse_answer(text: &str) -> Option<Decimal> {
+    text.parse::<Decimal>().or_else(|_| Decimal::from_scientific(text)).ok()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    // test midpoints that binary floating point would round the wrong way
+    fn test_round_tenths_midpoints() {
+        for (value, expected) in [(0.35, 4), (2.45, 25), (1.15, 12), (-0.35, -4), (0.25, 3), (0.04999, 0)] {
+            assert_eq!(round_tenths(value), Some(Decimal::new(expected, 1)), "{value}");
+        }
+        assert_eq!(round_tenths(0.35_f32), Some(Decimal::new(4, 1)));
+        assert_eq!(round_tenths(Decimal::new(1_049_999, 6)), Some(Decimal::new(10, 1)));
+        assert_eq!(round_tenths(f64::INFINITY), None);
+    }
+
+    #[test]
+    // test grading across numeric types
+    fn test_grade() {
+        assert_eq!(grade(21.11, 21.1), Grade::Correct);
+        assert_eq!(grade(21.15, 21.2), Grade::Correct);
+        assert_eq!(grade(21.15, 21.1), Grade::Incorrect);
+        assert_eq!(grade(0.2641, Decimal::new(3, 1)), Grade::Correct);
+        assert_eq!(grade(98.6_f32, 98.6_f64), Grade::Correct);
+        assert_eq!(grade(f64::NAN, f64::NAN), Grade::Incorrect);
+        assert_eq!(Grade::Correct.to_string(), "correct");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    // test grades serialize as their Display text
+    fn test_grade_serde() {
+        for grade in [Grade::Correct, Grade::Incorrect] {
+            let json = serde_json::to_string(&grade).unwrap();
+            assert_eq!(json, format!("\"{grade}\""));
+            assert_eq!(serde_json::from_str::<Grade>(&json).unwrap(), grade);
+        }
+    }
+}
@@ -1,10 +1,22 @@
 //! # Rustoleum
 //!
-//! A library for converting between temperature and volume units.
+//! A library for converting between temperature, volume, area, length, mass, time, energy,
+//! power, pressure, and data-size units.
 //!
 //! This library provides type-safe unit conversions for:
-//! - **Temperature units**: Kelvin, Celsius, Fahrenheit, and Rankine
-//! - **Volume units**: Liters, Tablespoons, Cubic Inches, Cups, Cubic Feet, and Gallons
+//! - **Temperature units**: Kelvin, Celsius, Fahrenheit, Rankine, Réaumur, Rømer, Delisle, and Newton
+//! - **Volume units**: Liters, Tablespoons, Cubic Inches, Cups, Cubic Feet, Gallons, Milliliters,
+//!   Teaspoons, Fluid Ounces, Pints, Quarts, Cubic Meters, and Barrels, plus Imperial (UK)
+//!   and US dry measures
+//! - **Area units**: Square Meters, Square Feet, Acres, Hectares, and Square Miles
+//! - **Length units**: Meters, Centimeters, Millimeters, Kilometers, Inches, Feet, Yards, and Miles
+//! - **Mass units**: Kilograms, Grams, Milligrams, Pounds, and Ounces
+//! - **Time units**: Seconds, Minutes, Hours, Days, and Weeks
+//! - **Energy units**: Joules, Kilojoules, Calories, Kilocalories, BTU, and Kilowatt Hours
+//! - **Power units**: Watts, Kilowatts, and Horsepower
+//! - **Pressure units**: Pascals, Kilopascals, Atmospheres, PSI, Millimeters of Mercury, and Bars
+//! - **Data-size units**: Bits, Bytes, and decimal (KB, MB, GB, TB) and binary (KiB, MiB, GiB,
+//!   TiB) multiples
 //!
 //! ## Example
 //!
@@ -19,9 +31,78 @@
 //! let result = convert(Unit::Liters, Unit::Gallons, 1.0);
 //! assert_eq!(result, Some(0.2641));
 //! ```
+//!
+//! Use [`explain`] to show the arithmetic behind a conversion step by step,
+//! rendered as plain text, Markdown, or LaTeX, and [`factor_label`] to write
+//! a multiplicative conversion as a dimensional-analysis chain of unit fractions.
+//! [`evaluate`] computes expressions such as `"3 gallons + 2 cups in liters"`,
+//! and [`CompoundUnit`] converts speeds, densities, flow rates, and other
+//! products and quotients of units, such as `"gal/min"` to `"L/s"`.
+//!
+//! ## `no_std`
+//!
+//! Everything that allocates is behind the default `std` feature. With
+//! `default-features = false` the crate is `#![no_std]` and provides the core
+//! conversions: [`Unit`], [`Dimension`], [`convert`] and the pairwise helpers,
+//! the conversion constants, [`Converter`], [`Number`], and [`grade`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
+use core::fmt;
+#[cfg(feature = "std")]
 use std::str::FromStr;
-use std::fmt;
+
+#[cfg(feature = "std")]
+mod batch;
+#[cfg(feature = "std")]
+mod compound;
+mod converter;
+#[cfg(feature = "std")]
+mod explain;
+#[cfg(feature = "std")]
+mod expr;
+#[cfg(feature = "std")]
+mod factor_label;
+mod grade;
+mod number;
+#[cfg(feature = "std")]
+mod parse;
+#[cfg(feature = "std")]
+mod prefix;
+#[cfg(feature = "std")]
+mod registry;
+#[cfg(feature = "std")]
+mod substance;
+#[cfg(feature = "std")]
+mod water;
+
+#[cfg(feature = "std")]
+pub use batch::{convert_slice, ConvertIterator, ConvertUnits, SliceConversionError};
+#[cfg(feature = "std")]
+pub use compound::{convert_compound, BaseDimensions, CompoundUnit, CompoundUnitError};
+pub use converter::{Converter, IncompatibleUnits};
+#[cfg(feature = "std")]
+pub use explain::{explain, Explanation, Format, FormatParseError, Operation, Step};
+#[cfg(feature = "std")]
+pub use expr::{evaluate, evaluate_with, ExprError, Quantity};
+#[cfg(feature = "std")]
+pub use factor_label::{
+    check_factor_label, factor_label, factor_label_via, FactorLabelChain, FactorLabelError,
+    UnitFraction, FACTOR_LABEL_TOLERANCE,
+};
+pub use grade::{grade, parse_answer, round_tenths, Grade};
+pub use number::Number;
+#[cfg(feature = "std")]
+pub use parse::{VolumeSystem, VolumeSystemParseError};
+#[cfg(feature = "std")]
+pub use prefix::{Prefix, PrefixedUnit};
+#[cfg(feature = "std")]
+pub use registry::{RatioUnit, RegistryError, UnitDefinition, UnitRegistry};
+#[cfg(feature = "std")]
+pub use substance::{convert_substance, explain_substance, Substance, SubstanceParseError};
+#[cfg(feature = "std")]
+pub use water::{water_density, WATER_MAX_CELSIUS, WATER_MIN_CELSIUS};
+pub use rust_decimal::Decimal;
 
 /// Epsilon value for floating-point comparison tolerance.
 ///
@@ -56,6 +137,12 @@ pub const CELSIUS_TO_FAHRENHEIT_RATIO: f64 = 9.0 / 5.0;
 pub const FAHRENHEIT_TO_CELSIUS_RATIO: f64 = 5.0 / 9.0;
 pub const KELVIN_TO_RANKINE_RATIO: f64 = 1.8;
 pub const RANKINE_TO_KELVIN_RATIO: f64 = 5.0 / 9.0;
+pub const CELSIUS_BOILING: f64 = 100.0;
+pub const REAUMUR_TO_CELSIUS_RATIO: f64 = 5.0 / 4.0;
+pub const ROMER_FREEZING: f64 = 7.5;
+pub const ROMER_TO_CELSIUS_RATIO: f64 = 40.0 / 21.0;
+pub const DELISLE_TO_CELSIUS_RATIO: f64 = 2.0 / 3.0;
+pub const NEWTON_TO_CELSIUS_RATIO: f64 = 100.0 / 33.0;
 
 // Volume conversion constants
 pub const LITERS_TO_TABLESPOONS: f64 = 67.628;
@@ -89,6 +176,93 @@ pub const GALLONS_TO_TABLESPOONS: f64 = 256.0;
 pub const GALLONS_TO_CUBIC_FEET: f64 = 0.133;
 pub const GALLONS_TO_CUPS: f64 = 16.0;
 
+// Exact length definitions, in meters. Area and volume units built from a
+// length (square feet, cubic inches, ...) are derived from these.
+pub const METERS_PER_INCH: f64 = 0.0254;
+pub const METERS_PER_FOOT: f64 = 0.3048;
+pub const METERS_PER_MILE: f64 = 1609.344;
+pub const METERS_PER_YARD: f64 = 0.9144;
+pub const METERS_PER_KILOMETER: f64 = 1000.0;
+pub const METERS_PER_CENTIMETER: f64 = 0.01;
+pub const METERS_PER_MILLIMETER: f64 = 0.001;
+
+// Exact mass definitions, in kilograms
+pub const KILOGRAMS_PER_GRAM: f64 = 0.001;
+pub const KILOGRAMS_PER_MILLIGRAM: f64 = 0.000_001;
+pub const KILOGRAMS_PER_POUND: f64 = 0.453_592_37;
+pub const KILOGRAMS_PER_OUNCE: f64 = KILOGRAMS_PER_POUND / 16.0;
+
+// Time definitions, in seconds
+pub const SECONDS_PER_MINUTE: f64 = 60.0;
+pub const SECONDS_PER_HOUR: f64 = 3600.0;
+pub const SECONDS_PER_DAY: f64 = 86_400.0;
+pub const SECONDS_PER_WEEK: f64 = 604_800.0;
+
+// Data-size definitions, in bytes. Decimal (SI) multiples are powers of
+// 1000; binary (IEC) multiples are powers of 1024.
+pub const BYTES_PER_BIT: f64 = 0.125;
+pub const BYTES_PER_KILOBYTE: f64 = 1e3;
+pub const BYTES_PER_MEGABYTE: f64 = 1e6;
+pub const BYTES_PER_GIGABYTE: f64 = 1e9;
+pub const BYTES_PER_TERABYTE: f64 = 1e12;
+pub const BYTES_PER_KIBIBYTE: f64 = 1024.0;
+pub const BYTES_PER_MEBIBYTE: f64 = 1_048_576.0;
+pub const BYTES_PER_GIBIBYTE: f64 = 1_073_741_824.0;
+pub const BYTES_PER_TEBIBYTE: f64 = 1_099_511_627_776.0;
+
+// Standard acceleration of gravity, in meters per second squared, relating
+// pound-force to pounds
+pub const STANDARD_GRAVITY: f64 = 9.806_65;
+
+// Energy definitions, in joules
+pub const JOULES_PER_KILOJOULE: f64 = 1000.0;
+pub const JOULES_PER_CALORIE: f64 = 4.184;
+pub const JOULES_PER_KILOCALORIE: f64 = 4184.0;
+pub const JOULES_PER_BTU: f64 = 1_055.055_852_62;
+pub const JOULES_PER_KILOWATT_HOUR: f64 = 3_600_000.0;
+
+// Power definitions, in watts
+pub const WATTS_PER_KILOWATT: f64 = 1000.0;
+pub const WATTS_PER_HORSEPOWER: f64 = 550.0 * METERS_PER_FOOT * KILOGRAMS_PER_POUND * STANDARD_GRAVITY;
+
+// Pressure definitions, in pascals
+pub const PASCALS_PER_KILOPASCAL: f64 = 1000.0;
+pub const PASCALS_PER_ATMOSPHERE: f64 = 101_325.0;
+pub const PASCALS_PER_PSI: f64 = KILOGRAMS_PER_POUND * STANDARD_GRAVITY / (METERS_PER_INCH * METERS_PER_INCH);
+pub const PASCALS_PER_MILLIMETER_OF_MERCURY: f64 = 133.322_387_415;
+pub const PASCALS_PER_BAR: f64 = 100_000.0;
+
+// Exact unit definitions, in liters
+pub const LITERS_PER_CUBIC_METER: f64 = 1000.0;
+pub const LITERS_PER_MILLILITER: f64 = 0.001;
+pub const LITERS_PER_CUBIC_INCH: f64 = METERS_PER_INCH * METERS_PER_INCH * METERS_PER_INCH * LITERS_PER_CUBIC_METER;
+pub const LITERS_PER_CUBIC_FOOT: f64 = METERS_PER_FOOT * METERS_PER_FOOT * METERS_PER_FOOT * LITERS_PER_CUBIC_METER;
+pub const LITERS_PER_GALLON: f64 = 231.0 * LITERS_PER_CUBIC_INCH;
+pub const LITERS_PER_QUART: f64 = LITERS_PER_GALLON / 4.0;
+pub const LITERS_PER_PINT: f64 = LITERS_PER_GALLON / 8.0;
+pub const LITERS_PER_CUP: f64 = LITERS_PER_GALLON / 16.0;
+pub const LITERS_PER_FLUID_OUNCE: f64 = LITERS_PER_GALLON / 128.0;
+pub const LITERS_PER_TABLESPOON: f64 = LITERS_PER_GALLON / 256.0;
+pub const LITERS_PER_TEASPOON: f64 = LITERS_PER_GALLON / 768.0;
+pub const LITERS_PER_BARREL: f64 = 42.0 * LITERS_PER_GALLON;
+pub const LITERS_PER_IMPERIAL_GALLON: f64 = 4.546_09;
+pub const LITERS_PER_IMPERIAL_QUART: f64 = 1.136_522_5;
+pub const LITERS_PER_IMPERIAL_PINT: f64 = 0.568_261_25;
+pub const LITERS_PER_IMPERIAL_CUP: f64 = 0.284_130_625;
+pub const LITERS_PER_IMPERIAL_FLUID_OUNCE: f64 = 0.028_413_062_5;
+pub const LITERS_PER_IMPERIAL_TABLESPOON: f64 = 0.017_758_164_062_5;
+pub const LITERS_PER_BUSHEL: f64 = 2150.42 * LITERS_PER_CUBIC_INCH;
+pub const LITERS_PER_PECK: f64 = LITERS_PER_BUSHEL / 4.0;
+pub const LITERS_PER_DRY_GALLON: f64 = LITERS_PER_BUSHEL / 8.0;
+pub const LITERS_PER_DRY_QUART: f64 = LITERS_PER_BUSHEL / 32.0;
+pub const LITERS_PER_DRY_PINT: f64 = LITERS_PER_BUSHEL / 64.0;
+
+// Exact unit definitions, in square meters
+pub const SQUARE_METERS_PER_SQUARE_FOOT: f64 = METERS_PER_FOOT * METERS_PER_FOOT;
+pub const SQUARE_METERS_PER_ACRE: f64 = 43_560.0 * SQUARE_METERS_PER_SQUARE_FOOT;
+pub const SQUARE_METERS_PER_HECTARE: f64 = 10_000.0;
+pub const SQUARE_METERS_PER_SQUARE_MILE: f64 = METERS_PER_MILE * METERS_PER_MILE;
+
 /// Error type for unit parsing operations.
 ///
 /// This error is returned when attempting to parse a string into a [`Unit`]
@@ -103,28 +277,106 @@ pub const GALLONS_TO_CUPS: f64 = 16.0;
 /// let result = Unit::from_str("invalid");
 /// assert!(matches!(result, Err(UnitParseError::UnknownUnit(_))));
 /// ```
+#[cfg(feature = "std")]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum UnitParseError {
     /// The provided string does not match any known unit.
     UnknownUnit(String),
+    /// An SI prefix was applied to a unit that is not metric, as in "kgal".
+    PrefixNotAllowed {
+        /// The prefix that was given.
+        prefix: Prefix,
+        /// The non-metric unit it was applied to.
+        unit: Unit,
+    },
+    /// The text names an SI-prefixed unit that is not built in, as in "Mg"
+    /// (megagrams); parse it as a [`PrefixedUnit`] instead.
+    Prefixed {
+        /// The prefix that was given.
+        prefix: Prefix,
+        /// The unit it was applied to.
+        unit: Unit,
+    },
+    /// A data-size symbol was written with a lower-case "b", as in "Mb",
+    /// which could mean either bits or bytes.
+    AmbiguousDataSize {
+        /// The text that was given.
+        text: String,
+        /// The byte unit the text would otherwise name.
+        unit: Unit,
+    },
 }
 
+#[cfg(feature = "std")]
+impl UnitParseError {
+    /// Returns known unit spellings close to the unrecognized input, best
+    /// match first.
+    ///
+    /// Suggestions are ranked by edit distance over every unit's names and
+    /// aliases, with at most one suggestion per unit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::Unit;
+    /// use std::str::FromStr;
+    ///
+    /// let err = Unit::from_str("farenheit").unwrap_err();
+    /// assert_eq!(err.suggestions(), vec!["fahrenheit"]);
+    /// ```
+    #[must_use]
+    pub fn suggestions(&self) -> Vec<String> {
+        match self {
+            Self::UnknownUnit(unit) => parse::suggestions(unit),
+            Self::PrefixNotAllowed { .. } | Self::Prefixed { .. } | Self::AmbiguousDataSize { .. } => Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
 impl fmt::Display for UnitParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::UnknownUnit(unit) => {
                 write!(f, "Unknown unit: '{unit}'")
             }
+            Self::PrefixNotAllowed { prefix, unit } => {
+                write!(f, "Unit '{}' does not accept the SI prefix '{prefix}'", unit.name(true))
+            }
+            Self::Prefixed { prefix, unit } => {
+                write!(
+                    f,
+                    "Unit '{}{}' is {}{}, which is not a built-in unit",
+                    prefix.symbol(),
+                    unit.symbol(),
+                    prefix.name(),
+                    unit.name(true)
+                )
+            }
+            Self::AmbiguousDataSize { text, unit } => {
+                write!(f, "Ambiguous unit '{text}': write '{}' for {} or 'bit' for bits", unit.symbol(), unit.name(true))
+            }
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for UnitParseError {}
 
 /// Type-safe representation of measurement units.
 ///
-/// This enum represents all supported units for temperature and volume conversions.
-/// It can be parsed from strings (case-insensitive) and used with the [`convert`] function.
+/// This enum represents all supported units for temperature, volume, area,
+/// length, mass, time, energy, power, pressure, and data-size conversions.
+/// It can be parsed from strings and used with the [`convert`] function.
+///
+/// Parsing is case-insensitive and accepts names, abbreviations, and symbols
+/// from each unit's [`aliases`](Unit::aliases) table: singular or plural
+/// forms, British spellings ("litre"), degree signs ("°F"), Unicode
+/// superscripts ("in³"), and spaces, hyphens, or underscores between words
+/// ("cu ft", "cubic-feet", "cubic_feet"). The exception is SI prefix
+/// symbols, which are case-sensitive everywhere: "Mg" is megagrams, not
+/// milligrams, and is rejected as a `Unit` because it is not built in.
 ///
 /// # Supported Units
 ///
@@ -133,29 +385,94 @@ impl std::error::Error for UnitParseError {}
 /// - `Celsius` - Metric temperature scale
 /// - `Fahrenheit` - Imperial temperature scale
 /// - `Rankine` - Absolute temperature scale (Fahrenheit-based)
+/// - `Reaumur` - Historical scale with 80 °Ré between freezing and boiling
+/// - `Romer` - Historical scale with water freezing at 7.5 °Rø
+/// - `Delisle` - Historical inverted scale with water boiling at 0 °De
+/// - `Newton` - Historical scale with water boiling at 33 °N
 ///
 /// ## Volume Units
 /// - `Liters` - Metric volume unit
 /// - `Tablespoons` - US customary volume unit
-/// - `CubicInches` - Imperial volume unit (also accepts "CUBIC-INCHES" or "CUBICINCHES")
+/// - `CubicInches` - Imperial volume unit (also accepts "cubic-inches", "cu in", or "in³")
 /// - `Cups` - US customary volume unit
-/// - `CubicFeet` - Imperial volume unit (also accepts "CUBIC-FEET" or "CUBICFEET")
+/// - `CubicFeet` - Imperial volume unit (also accepts "cubic-feet", "cu ft", or "ft³")
 /// - `Gallons` - US customary volume unit
+/// - `Milliliters` - Metric volume unit (also accepts "mL" or "cc")
+/// - `Teaspoons` - US customary volume unit
+/// - `FluidOunces` - US customary volume unit (also accepts "fl oz")
+/// - `Pints` - US customary volume unit
+/// - `Quarts` - US customary volume unit
+/// - `CubicMeters` - Metric volume unit (also accepts "cubic-meters" or "m³")
+/// - `Barrels` - Petroleum barrel of 42 US gallons (also accepts "bbl")
+/// - `ImperialGallons`, `ImperialQuarts`, `ImperialPints`, `ImperialCups`,
+///   `ImperialFluidOunces`, `ImperialTablespoons` - Imperial (UK) volume units
+///   (also accepts "imperial gallon", "imp gal", or "UK gallon")
+/// - `DryPints`, `DryQuarts`, `DryGallons`, `Pecks`, `Bushels` - US dry measures
+///
+/// ## Area Units
+/// - `SquareMeters` - Metric area unit (also accepts "sq m" or "m²")
+/// - `SquareFeet` - Imperial area unit (also accepts "sq ft" or "ft²")
+/// - `Acres` - US customary land area unit (43,560 square feet)
+/// - `Hectares` - Metric land area unit (10,000 square meters)
+/// - `SquareMiles` - Imperial area unit (also accepts "sq mi" or "mi²")
+///
+/// ## Length Units
+/// - `Meters`, `Centimeters`, `Millimeters`, `Kilometers` - Metric length units
+/// - `Inches`, `Feet`, `Yards`, `Miles` - Imperial length units
+///
+/// ## Mass Units
+/// - `Kilograms`, `Grams`, `Milligrams` - Metric mass units
+/// - `Pounds`, `Ounces` - Avoirdupois mass units
+///
+/// ## Time Units
+/// - `Seconds`, `Minutes`, `Hours`, `Days`, `Weeks`
+///
+/// ## Energy Units
+/// - `Joules`, `Kilojoules` - SI energy units
+/// - `Calories`, `Kilocalories` - Thermochemical calorie (4.184 J) and food Calorie
+/// - `Btu` - International Table British thermal unit
+/// - `KilowattHours` - Electrical energy unit
+///
+/// ## Power Units
+/// - `Watts`, `Kilowatts` - SI power units
+/// - `Horsepower` - Mechanical horsepower (550 ft·lbf/s)
+///
+/// ## Pressure Units
+/// - `Pascals`, `Kilopascals` - SI pressure units
+/// - `Atmospheres` - Standard atmosphere
+/// - `Psi` - Pounds (force) per square inch
+/// - `MillimetersOfMercury` - Manometric pressure unit (also accepts "mmHg")
+/// - `Bars` - Metric pressure unit (100 kPa)
+///
+/// ## Data-Size Units
+/// - `Bits`, `Bytes`
+/// - `Kilobytes`, `Megabytes`, `Gigabytes`, `Terabytes` - Decimal (SI)
+///   multiples of 1000, written "KB", "MB", "GB", "TB"
+/// - `Kibibytes`, `Mebibytes`, `Gibibytes`, `Tebibytes` - Binary (IEC)
+///   multiples of 1024, written "KiB", "MiB", "GiB", "TiB"
+///
+/// Byte symbols must end in an upper-case "B" ("kB" and "KB" both mean
+/// kilobytes); forms such as "b" or "Mb", which usually mean bits, are
+/// rejected as ambiguous. Bits are written "bit".
+///
+/// The unqualified US customary units (`Gallons`, `Quarts`, `Pints`, `Cups`,
+/// `FluidOunces`, `Tablespoons`) are the US liquid measures. Their bare names,
+/// such as "gallon" or "pt", mean the US measure when parsed with
+/// [`Unit::from_str`](FromStr::from_str) and the Imperial one when parsed
+/// with [`Unit::from_str_with`] in [`VolumeSystem::Imperial`]; names
+/// qualified with "US" always mean the US unit.
 ///
 /// # Example
 ///
 /// ```rust
-/// use rustoleum::Unit;
-/// use std::str::FromStr;
-///
-/// // Parse from string (case-insensitive)
-/// let unit = Unit::from_str("celsius").unwrap();
-/// assert_eq!(unit, Unit::Celsius);
+/// use rustoleum::{convert, Unit};
 ///
-/// // Use in conversions
-/// use rustoleum::convert;
-/// let result = convert(Unit::Celsius, Unit::Fahrenheit, 100.0);
+/// assert_eq!(convert(Unit::Celsius, Unit::Fahrenheit, 100.0), Some(212.0));
+/// assert_eq!(convert(Unit::Celsius, Unit::Liters, 1.0), None);
 /// ```
+///
+/// With the `std` feature, units are also parsed from names and symbols
+/// through [`FromStr`](core::str::FromStr).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Unit {
     /// Kelvin - Absolute temperature scale (0 K = absolute zero)
@@ -166,6 +483,14 @@ pub enum Unit {
     Fahrenheit,
     /// Rankine - Absolute temperature scale based on Fahrenheit (0°R = absolute zero)
     Rankine,
+    /// Réaumur - Historical scale (0°Ré = freezing, 80°Ré = boiling point of water)
+    Reaumur,
+    /// Rømer - Historical scale (7.5°Rø = freezing, 60°Rø = boiling point of water)
+    Romer,
+    /// Delisle - Historical inverted scale (0°De = boiling, 150°De = freezing point of water)
+    Delisle,
+    /// Newton - Historical scale (0°N = freezing, 33°N = boiling point of water)
+    Newton,
     /// Liters - Metric volume unit
     Liters,
     /// Tablespoons - US customary volume unit
@@ -178,25 +503,846 @@ pub enum Unit {
     CubicFeet,
     /// Gallons - US customary volume unit
     Gallons,
+    /// Milliliters - Metric volume unit (1/1000 liter)
+    Milliliters,
+    /// Teaspoons - US customary volume unit (1/3 tablespoon)
+    Teaspoons,
+    /// Fluid Ounces - US customary volume unit (2 tablespoons)
+    FluidOunces,
+    /// Pints - US customary volume unit (2 cups)
+    Pints,
+    /// Quarts - US customary volume unit (2 pints)
+    Quarts,
+    /// Cubic Meters - Metric volume unit (1000 liters)
+    CubicMeters,
+    /// Barrels - Petroleum barrel (42 gallons)
+    Barrels,
+    /// Imperial Gallons - Imperial (UK) volume unit (4.54609 liters)
+    ImperialGallons,
+    /// Imperial Quarts - Imperial (UK) volume unit (1/4 imperial gallon)
+    ImperialQuarts,
+    /// Imperial Pints - Imperial (UK) volume unit (20 imperial fluid ounces)
+    ImperialPints,
+    /// Imperial Cups - Imperial (UK) volume unit (1/2 imperial pint)
+    ImperialCups,
+    /// Imperial Fluid Ounces - Imperial (UK) volume unit (1/160 imperial gallon)
+    ImperialFluidOunces,
+    /// Imperial Tablespoons - Imperial (UK) volume unit (5/8 imperial fluid ounce)
+    ImperialTablespoons,
+    /// Dry Pints - US dry volume unit (1/64 bushel)
+    DryPints,
+    /// Dry Quarts - US dry volume unit (2 dry pints)
+    DryQuarts,
+    /// Dry Gallons - US dry volume unit (4 dry quarts)
+    DryGallons,
+    /// Pecks - US dry volume unit (2 dry gallons)
+    Pecks,
+    /// Bushels - US dry volume unit (4 pecks, 2150.42 cubic inches)
+    Bushels,
+    /// Square Meters - Metric area unit
+    SquareMeters,
+    /// Square Feet - Imperial area unit
+    SquareFeet,
+    /// Acres - US customary land area unit (43,560 square feet)
+    Acres,
+    /// Hectares - Metric land area unit (10,000 square meters)
+    Hectares,
+    /// Square Miles - Imperial area unit (640 acres)
+    SquareMiles,
+    /// Meters - Metric length unit
+    Meters,
+    /// Centimeters - Metric length unit (1/100 meter)
+    Centimeters,
+    /// Millimeters - Metric length unit (1/1000 meter)
+    Millimeters,
+    /// Kilometers - Metric length unit (1000 meters)
+    Kilometers,
+    /// Inches - Imperial length unit (2.54 centimeters)
+    Inches,
+    /// Feet - Imperial length unit (12 inches)
+    Feet,
+    /// Yards - Imperial length unit (3 feet)
+    Yards,
+    /// Miles - Imperial length unit (5280 feet)
+    Miles,
+    /// Kilograms - Metric mass unit
+    Kilograms,
+    /// Grams - Metric mass unit (1/1000 kilogram)
+    Grams,
+    /// Milligrams - Metric mass unit (1/1000 gram)
+    Milligrams,
+    /// Pounds - Avoirdupois mass unit (0.45359237 kilograms)
+    Pounds,
+    /// Ounces - Avoirdupois mass unit (1/16 pound)
+    Ounces,
+    /// Seconds - Time unit
+    Seconds,
+    /// Minutes - Time unit (60 seconds)
+    Minutes,
+    /// Hours - Time unit (60 minutes)
+    Hours,
+    /// Days - Time unit (24 hours)
+    Days,
+    /// Weeks - Time unit (7 days)
+    Weeks,
+    /// Joules - SI energy unit
+    Joules,
+    /// Kilojoules - SI energy unit (1000 joules)
+    Kilojoules,
+    /// Calories - Thermochemical calorie (4.184 joules)
+    Calories,
+    /// Kilocalories - Food Calorie (1000 calories)
+    Kilocalories,
+    /// BTU - International Table British thermal unit
+    Btu,
+    /// Kilowatt Hours - Electrical energy unit (3.6 megajoules)
+    KilowattHours,
+    /// Watts - SI power unit
+    Watts,
+    /// Kilowatts - SI power unit (1000 watts)
+    Kilowatts,
+    /// Horsepower - Mechanical horsepower (550 foot-pounds force per second)
+    Horsepower,
+    /// Pascals - SI pressure unit
+    Pascals,
+    /// Kilopascals - SI pressure unit (1000 pascals)
+    Kilopascals,
+    /// Atmospheres - Standard atmosphere (101,325 pascals)
+    Atmospheres,
+    /// PSI - Pounds (force) per square inch
+    Psi,
+    /// Millimeters of Mercury - Manometric pressure unit
+    MillimetersOfMercury,
+    /// Bars - Metric pressure unit (100,000 pascals)
+    Bars,
+    /// Bits - Data-size unit (1/8 byte)
+    Bits,
+    /// Bytes - Data-size unit
+    Bytes,
+    /// Kilobytes - Decimal data-size unit (1000 bytes)
+    Kilobytes,
+    /// Megabytes - Decimal data-size unit (1000 kilobytes)
+    Megabytes,
+    /// Gigabytes - Decimal data-size unit (1000 megabytes)
+    Gigabytes,
+    /// Terabytes - Decimal data-size unit (1000 gigabytes)
+    Terabytes,
+    /// Kibibytes - Binary data-size unit (1024 bytes)
+    Kibibytes,
+    /// Mebibytes - Binary data-size unit (1024 kibibytes)
+    Mebibytes,
+    /// Gibibytes - Binary data-size unit (1024 mebibytes)
+    Gibibytes,
+    /// Tebibytes - Binary data-size unit (1024 gibibytes)
+    Tebibytes,
+}
+
+/// Every supported unit, grouped by dimension in declaration order.
+const ALL_UNITS: [Unit; 80] = [
+    Unit::Kelvin,
+    Unit::Celsius,
+    Unit::Fahrenheit,
+    Unit::Rankine,
+    Unit::Reaumur,
+    Unit::Romer,
+    Unit::Delisle,
+    Unit::Newton,
+    Unit::Liters,
+    Unit::Tablespoons,
+    Unit::CubicInches,
+    Unit::Cups,
+    Unit::CubicFeet,
+    Unit::Gallons,
+    Unit::Milliliters,
+    Unit::Teaspoons,
+    Unit::FluidOunces,
+    Unit::Pints,
+    Unit::Quarts,
+    Unit::CubicMeters,
+    Unit::Barrels,
+    Unit::ImperialGallons,
+    Unit::ImperialQuarts,
+    Unit::ImperialPints,
+    Unit::ImperialCups,
+    Unit::ImperialFluidOunces,
+    Unit::ImperialTablespoons,
+    Unit::DryPints,
+    Unit::DryQuarts,
+    Unit::DryGallons,
+    Unit::Pecks,
+    Unit::Bushels,
+    Unit::SquareMeters,
+    Unit::SquareFeet,
+    Unit::Acres,
+    Unit::Hectares,
+    Unit::SquareMiles,
+    Unit::Meters,
+    Unit::Centimeters,
+    Unit::Millimeters,
+    Unit::Kilometers,
+    Unit::Inches,
+    Unit::Feet,
+    Unit::Yards,
+    Unit::Miles,
+    Unit::Kilograms,
+    Unit::Grams,
+    Unit::Milligrams,
+    Unit::Pounds,
+    Unit::Ounces,
+    Unit::Seconds,
+    Unit::Minutes,
+    Unit::Hours,
+    Unit::Days,
+    Unit::Weeks,
+    Unit::Joules,
+    Unit::Kilojoules,
+    Unit::Calories,
+    Unit::Kilocalories,
+    Unit::Btu,
+    Unit::KilowattHours,
+    Unit::Watts,
+    Unit::Kilowatts,
+    Unit::Horsepower,
+    Unit::Pascals,
+    Unit::Kilopascals,
+    Unit::Atmospheres,
+    Unit::Psi,
+    Unit::MillimetersOfMercury,
+    Unit::Bars,
+    Unit::Bits,
+    Unit::Bytes,
+    Unit::Kilobytes,
+    Unit::Megabytes,
+    Unit::Gigabytes,
+    Unit::Terabytes,
+    Unit::Kibibytes,
+    Unit::Mebibytes,
+    Unit::Gibibytes,
+    Unit::Tebibytes,
+];
+
+impl Unit {
+    /// Returns every supported unit, grouped by dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::Unit;
+    ///
+    /// assert!(Unit::all().contains(&Unit::Gallons));
+    /// ```
+    #[must_use]
+    pub const fn all() -> &'static [Unit] {
+        &ALL_UNITS
+    }
+
+    /// Returns the physical dimension this unit measures.
+    ///
+    /// Only units of the same dimension can be converted into each other.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::{Dimension, Unit};
+    ///
+    /// assert_eq!(Unit::Rankine.dimension(), Dimension::Temperature);
+    /// assert_eq!(Unit::Cups.dimension(), Dimension::Volume);
+    /// ```
+    #[must_use]
+    pub const fn dimension(self) -> Dimension {
+        match self {
+            Self::Kelvin
+            | Self::Celsius
+            | Self::Fahrenheit
+            | Self::Rankine
+            | Self::Reaumur
+            | Self::Romer
+            | Self::Delisle
+            | Self::Newton => Dimension::Temperature,
+            Self::Liters
+            | Self::Tablespoons
+            | Self::CubicInches
+            | Self::Cups
+            | Self::CubicFeet
+            | Self::Gallons
+            | Self::Milliliters
+            | Self::Teaspoons
+            | Self::FluidOunces
+            | Self::Pints
+            | Self::Quarts
+            | Self::CubicMeters
+            | Self::Barrels
+            | Self::ImperialGallons
+            | Self::ImperialQuarts
+            | Self::ImperialPints
+            | Self::ImperialCups
+            | Self::ImperialFluidOunces
+            | Self::ImperialTablespoons
+            | Self::DryPints
+            | Self::DryQuarts
+            | Self::DryGallons
+            | Self::Pecks
+            | Self::Bushels => Dimension::Volume,
+            Self::SquareMeters
+            | Self::SquareFeet
+            | Self::Acres
+            | Self::Hectares
+            | Self::SquareMiles => Dimension::Area,
+            Self::Meters
+            | Self::Centimeters
+            | Self::Millimeters
+            | Self::Kilometers
+            | Self::Inches
+            | Self::Feet
+            | Self::Yards
+            | Self::Miles => Dimension::Length,
+            Self::Kilograms | Self::Grams | Self::Milligrams | Self::Pounds | Self::Ounces => Dimension::Mass,
+            Self::Seconds | Self::Minutes | Self::Hours | Self::Days | Self::Weeks => Dimension::Time,
+            Self::Joules
+            | Self::Kilojoules
+            | Self::Calories
+            | Self::Kilocalories
+            | Self::Btu
+            | Self::KilowattHours => Dimension::Energy,
+            Self::Watts | Self::Kilowatts | Self::Horsepower => Dimension::Power,
+            Self::Pascals
+            | Self::Kilopascals
+            | Self::Atmospheres
+            | Self::Psi
+            | Self::MillimetersOfMercury
+            | Self::Bars => Dimension::Pressure,
+            Self::Bits
+            | Self::Bytes
+            | Self::Kilobytes
+            | Self::Megabytes
+            | Self::Gigabytes
+            | Self::Terabytes
+            | Self::Kibibytes
+            | Self::Mebibytes
+            | Self::Gibibytes
+            | Self::Tebibytes => Dimension::DataSize,
+        }
+    }
+
+    /// Returns the conventional symbol or abbreviation for this unit.
+    ///
+    /// Every symbol is accepted by [`Unit::from_str`](FromStr::from_str).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::Unit;
+    ///
+    /// assert_eq!(Unit::Fahrenheit.symbol(), "°F");
+    /// assert_eq!(Unit::CubicFeet.symbol(), "ft³");
+    /// ```
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Kelvin => "K",
+            Self::Celsius => "°C",
+            Self::Fahrenheit => "°F",
+            Self::Rankine => "°R",
+            Self::Reaumur => "°Ré",
+            Self::Romer => "°Rø",
+            Self::Delisle => "°De",
+            Self::Newton => "°N",
+            Self::Liters => "L",
+            Self::Tablespoons => "tbsp",
+            Self::CubicInches => "in³",
+            Self::Cups => "cup",
+            Self::CubicFeet => "ft³",
+            Self::Gallons => "gal",
+            Self::Milliliters => "mL",
+            Self::Teaspoons => "tsp",
+            Self::FluidOunces => "fl oz",
+            Self::Pints => "pt",
+            Self::Quarts => "qt",
+            Self::CubicMeters => "m³",
+            Self::Barrels => "bbl",
+            Self::ImperialGallons => "imp gal",
+            Self::ImperialQuarts => "imp qt",
+            Self::ImperialPints => "imp pt",
+            Self::ImperialCups => "imp cup",
+            Self::ImperialFluidOunces => "imp fl oz",
+            Self::ImperialTablespoons => "imp tbsp",
+            Self::DryPints => "dry pt",
+            Self::DryQuarts => "dry qt",
+            Self::DryGallons => "dry gal",
+            Self::Pecks => "pk",
+            Self::Bushels => "bu",
+            Self::SquareMeters => "m²",
+            Self::SquareFeet => "ft²",
+            Self::Acres => "ac",
+            Self::Hectares => "ha",
+            Self::SquareMiles => "mi²",
+            Self::Meters => "m",
+            Self::Centimeters => "cm",
+            Self::Millimeters => "mm",
+            Self::Kilometers => "km",
+            Self::Inches => "in",
+            Self::Feet => "ft",
+            Self::Yards => "yd",
+            Self::Miles => "mi",
+            Self::Kilograms => "kg",
+            Self::Grams => "g",
+            Self::Milligrams => "mg",
+            Self::Pounds => "lb",
+            Self::Ounces => "oz",
+            Self::Seconds => "s",
+            Self::Minutes => "min",
+            Self::Hours => "h",
+            Self::Days => "d",
+            Self::Weeks => "wk",
+            Self::Joules => "J",
+            Self::Kilojoules => "kJ",
+            Self::Calories => "cal",
+            Self::Kilocalories => "kcal",
+            Self::Btu => "BTU",
+            Self::KilowattHours => "kWh",
+            Self::Watts => "W",
+            Self::Kilowatts => "kW",
+            Self::Horsepower => "hp",
+            Self::Pascals => "Pa",
+            Self::Kilopascals => "kPa",
+            Self::Atmospheres => "atm",
+            Self::Psi => "psi",
+            Self::MillimetersOfMercury => "mmHg",
+            Self::Bars => "bar",
+            Self::Bits => "bit",
+            Self::Bytes => "B",
+            Self::Kilobytes => "KB",
+            Self::Megabytes => "MB",
+            Self::Gigabytes => "GB",
+            Self::Terabytes => "TB",
+            Self::Kibibytes => "KiB",
+            Self::Mebibytes => "MiB",
+            Self::Gibibytes => "GiB",
+            Self::Tebibytes => "TiB",
+        }
+    }
+
+    /// Returns the display name of this unit, singular or plural.
+    ///
+    /// Every name is accepted by [`Unit::from_str`](FromStr::from_str).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::Unit;
+    ///
+    /// assert_eq!(Unit::CubicFeet.name(false), "cubic foot");
+    /// assert_eq!(Unit::CubicFeet.name(true), "cubic feet");
+    /// assert_eq!(Unit::Celsius.name(true), "degrees Celsius");
+    /// ```
+    #[must_use]
+    pub const fn name(self, plural: bool) -> &'static str {
+        let (singular, plural_name) = match self {
+            Self::Kelvin => ("kelvin", "kelvins"),
+            Self::Celsius => ("degree Celsius", "degrees Celsius"),
+            Self::Fahrenheit => ("degree Fahrenheit", "degrees Fahrenheit"),
+            Self::Rankine => ("degree Rankine", "degrees Rankine"),
+            Self::Reaumur => ("degree Réaumur", "degrees Réaumur"),
+            Self::Romer => ("degree Rømer", "degrees Rømer"),
+            Self::Delisle => ("degree Delisle", "degrees Delisle"),
+            Self::Newton => ("degree Newton", "degrees Newton"),
+            Self::Liters => ("liter", "liters"),
+            Self::Tablespoons => ("tablespoon", "tablespoons"),
+            Self::CubicInches => ("cubic inch", "cubic inches"),
+            Self::Cups => ("cup", "cups"),
+            Self::CubicFeet => ("cubic foot", "cubic feet"),
+            Self::Gallons => ("gallon", "gallons"),
+            Self::Milliliters => ("milliliter", "milliliters"),
+            Self::Teaspoons => ("teaspoon", "teaspoons"),
+            Self::FluidOunces => ("fluid ounce", "fluid ounces"),
+            Self::Pints => ("pint", "pints"),
+            Self::Quarts => ("quart", "quarts"),
+            Self::CubicMeters => ("cubic meter", "cubic meters"),
+            Self::Barrels => ("barrel", "barrels"),
+            Self::ImperialGallons => ("imperial gallon", "imperial gallons"),
+            Self::ImperialQuarts => ("imperial quart", "imperial quarts"),
+            Self::ImperialPints => ("imperial pint", "imperial pints"),
+            Self::ImperialCups => ("imperial cup", "imperial cups"),
+            Self::ImperialFluidOunces => ("imperial fluid ounce", "imperial fluid ounces"),
+            Self::ImperialTablespoons => ("imperial tablespoon", "imperial tablespoons"),
+            Self::DryPints => ("dry pint", "dry pints"),
+            Self::DryQuarts => ("dry quart", "dry quarts"),
+            Self::DryGallons => ("dry gallon", "dry gallons"),
+            Self::Pecks => ("peck", "pecks"),
+            Self::Bushels => ("bushel", "bushels"),
+            Self::SquareMeters => ("square meter", "square meters"),
+            Self::SquareFeet => ("square foot", "square feet"),
+            Self::Acres => ("acre", "acres"),
+            Self::Hectares => ("hectare", "hectares"),
+            Self::SquareMiles => ("square mile", "square miles"),
+            Self::Meters => ("meter", "meters"),
+            Self::Centimeters => ("centimeter", "centimeters"),
+            Self::Millimeters => ("millimeter", "millimeters"),
+            Self::Kilometers => ("kilometer", "kilometers"),
+            Self::Inches => ("inch", "inches"),
+            Self::Feet => ("foot", "feet"),
+            Self::Yards => ("yard", "yards"),
+            Self::Miles => ("mile", "miles"),
+            Self::Kilograms => ("kilogram", "kilograms"),
+            Self::Grams => ("gram", "grams"),
+            Self::Milligrams => ("milligram", "milligrams"),
+            Self::Pounds => ("pound", "pounds"),
+            Self::Ounces => ("ounce", "ounces"),
+            Self::Seconds => ("second", "seconds"),
+            Self::Minutes => ("minute", "minutes"),
+            Self::Hours => ("hour", "hours"),
+            Self::Days => ("day", "days"),
+            Self::Weeks => ("week", "weeks"),
+            Self::Joules => ("joule", "joules"),
+            Self::Kilojoules => ("kilojoule", "kilojoules"),
+            Self::Calories => ("calorie", "calories"),
+            Self::Kilocalories => ("kilocalorie", "kilocalories"),
+            Self::Btu => ("British thermal unit", "British thermal units"),
+            Self::KilowattHours => ("kilowatt hour", "kilowatt hours"),
+            Self::Watts => ("watt", "watts"),
+            Self::Kilowatts => ("kilowatt", "kilowatts"),
+            Self::Horsepower => ("horsepower", "horsepower"),
+            Self::Pascals => ("pascal", "pascals"),
+            Self::Kilopascals => ("kilopascal", "kilopascals"),
+            Self::Atmospheres => ("atmosphere", "atmospheres"),
+            Self::Psi => ("pound per square inch", "pounds per square inch"),
+            Self::MillimetersOfMercury => ("millimeter of mercury", "millimeters of mercury"),
+            Self::Bars => ("bar", "bars"),
+            Self::Bits => ("bit", "bits"),
+            Self::Bytes => ("byte", "bytes"),
+            Self::Kilobytes => ("kilobyte", "kilobytes"),
+            Self::Megabytes => ("megabyte", "megabytes"),
+            Self::Gigabytes => ("gigabyte", "gigabytes"),
+            Self::Terabytes => ("terabyte", "terabytes"),
+            Self::Kibibytes => ("kibibyte", "kibibytes"),
+            Self::Mebibytes => ("mebibyte", "mebibytes"),
+            Self::Gibibytes => ("gibibyte", "gibibytes"),
+            Self::Tebibytes => ("tebibyte", "tebibytes"),
+        };
+        if plural {
+            plural_name
+        } else {
+            singular
+        }
+    }
+}
+
+impl Unit {
+    /// Returns the factor relating this unit to its dimension's
+    /// [base unit](Dimension::base_unit).
+    ///
+    /// A value `v` in this unit is `v * scale() + offset()` in the base unit.
+    /// Scales use exact unit definitions, so conversions computed from them
+    /// can differ slightly from [`convert`], which uses the rounded
+    /// worksheet constants above.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::Unit;
+    ///
+    /// // 1 gallon is exactly 3.785411784 liters
+    /// assert_eq!(Unit::Gallons.scale(), 3.785_411_784);
+    /// ```
+    #[must_use]
+    pub const fn scale(self) -> f64 {
+        match self {
+            Self::Kelvin | Self::Celsius => 1.0,
+            Self::Fahrenheit | Self::Rankine => RANKINE_TO_KELVIN_RATIO,
+            Self::Reaumur => REAUMUR_TO_CELSIUS_RATIO,
+            Self::Romer => ROMER_TO_CELSIUS_RATIO,
+            Self::Delisle => -DELISLE_TO_CELSIUS_RATIO,
+            Self::Newton => NEWTON_TO_CELSIUS_RATIO,
+            Self::Liters => 1.0,
+            Self::Tablespoons => LITERS_PER_TABLESPOON,
+            Self::CubicInches => LITERS_PER_CUBIC_INCH,
+            Self::Cups => LITERS_PER_CUP,
+            Self::CubicFeet => LITERS_PER_CUBIC_FOOT,
+            Self::Gallons => LITERS_PER_GALLON,
+            Self::Milliliters => LITERS_PER_MILLILITER,
+            Self::Teaspoons => LITERS_PER_TEASPOON,
+            Self::FluidOunces => LITERS_PER_FLUID_OUNCE,
+            Self::Pints => LITERS_PER_PINT,
+            Self::Quarts => LITERS_PER_QUART,
+            Self::CubicMeters => LITERS_PER_CUBIC_METER,
+            Self::Barrels => LITERS_PER_BARREL,
+            Self::ImperialGallons => LITERS_PER_IMPERIAL_GALLON,
+            Self::ImperialQuarts => LITERS_PER_IMPERIAL_QUART,
+            Self::ImperialPints => LITERS_PER_IMPERIAL_PINT,
+            Self::ImperialCups => LITERS_PER_IMPERIAL_CUP,
+            Self::ImperialFluidOunces => LITERS_PER_IMPERIAL_FLUID_OUNCE,
+            Self::ImperialTablespoons => LITERS_PER_IMPERIAL_TABLESPOON,
+            Self::DryPints => LITERS_PER_DRY_PINT,
+            Self::DryQuarts => LITERS_PER_DRY_QUART,
+            Self::DryGallons => LITERS_PER_DRY_GALLON,
+            Self::Pecks => LITERS_PER_PECK,
+            Self::Bushels => LITERS_PER_BUSHEL,
+            Self::SquareMeters => 1.0,
+            Self::SquareFeet => SQUARE_METERS_PER_SQUARE_FOOT,
+            Self::Acres => SQUARE_METERS_PER_ACRE,
+            Self::Hectares => SQUARE_METERS_PER_HECTARE,
+            Self::SquareMiles => SQUARE_METERS_PER_SQUARE_MILE,
+            Self::Meters => 1.0,
+            Self::Centimeters => METERS_PER_CENTIMETER,
+            Self::Millimeters => METERS_PER_MILLIMETER,
+            Self::Kilometers => METERS_PER_KILOMETER,
+            Self::Inches => METERS_PER_INCH,
+            Self::Feet => METERS_PER_FOOT,
+            Self::Yards => METERS_PER_YARD,
+            Self::Miles => METERS_PER_MILE,
+            Self::Kilograms => 1.0,
+            Self::Grams => KILOGRAMS_PER_GRAM,
+            Self::Milligrams => KILOGRAMS_PER_MILLIGRAM,
+            Self::Pounds => KILOGRAMS_PER_POUND,
+            Self::Ounces => KILOGRAMS_PER_OUNCE,
+            Self::Seconds => 1.0,
+            Self::Minutes => SECONDS_PER_MINUTE,
+            Self::Hours => SECONDS_PER_HOUR,
+            Self::Days => SECONDS_PER_DAY,
+            Self::Weeks => SECONDS_PER_WEEK,
+            Self::Joules => 1.0,
+            Self::Kilojoules => JOULES_PER_KILOJOULE,
+            Self::Calories => JOULES_PER_CALORIE,
+            Self::Kilocalories => JOULES_PER_KILOCALORIE,
+            Self::Btu => JOULES_PER_BTU,
+            Self::KilowattHours => JOULES_PER_KILOWATT_HOUR,
+            Self::Watts => 1.0,
+            Self::Kilowatts => WATTS_PER_KILOWATT,
+            Self::Horsepower => WATTS_PER_HORSEPOWER,
+            Self::Pascals => 1.0,
+            Self::Kilopascals => PASCALS_PER_KILOPASCAL,
+            Self::Atmospheres => PASCALS_PER_ATMOSPHERE,
+            Self::Psi => PASCALS_PER_PSI,
+            Self::MillimetersOfMercury => PASCALS_PER_MILLIMETER_OF_MERCURY,
+            Self::Bars => PASCALS_PER_BAR,
+            Self::Bits => BYTES_PER_BIT,
+            Self::Bytes => 1.0,
+            Self::Kilobytes => BYTES_PER_KILOBYTE,
+            Self::Megabytes => BYTES_PER_MEGABYTE,
+            Self::Gigabytes => BYTES_PER_GIGABYTE,
+            Self::Terabytes => BYTES_PER_TERABYTE,
+            Self::Kibibytes => BYTES_PER_KIBIBYTE,
+            Self::Mebibytes => BYTES_PER_MEBIBYTE,
+            Self::Gibibytes => BYTES_PER_GIBIBYTE,
+            Self::Tebibytes => BYTES_PER_TEBIBYTE,
+        }
+    }
+
+    /// Returns the offset relating this unit to its dimension's
+    /// [base unit](Dimension::base_unit); see [`Unit::scale`].
+    ///
+    /// Only temperature scales with a zero point other than absolute zero
+    /// have an offset.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::Unit;
+    ///
+    /// // 0 °C is 273.15 K
+    /// assert_eq!(Unit::Celsius.offset(), 273.15);
+    /// ```
+    #[must_use]
+    pub const fn offset(self) -> f64 {
+        match self {
+            Self::Celsius | Self::Reaumur | Self::Newton => ABSOLUTE_ZERO_CELSIUS,
+            Self::Fahrenheit => ABSOLUTE_ZERO_CELSIUS - FAHRENHEIT_FREEZING * FAHRENHEIT_TO_CELSIUS_RATIO,
+            Self::Romer => ABSOLUTE_ZERO_CELSIUS - ROMER_FREEZING * ROMER_TO_CELSIUS_RATIO,
+            Self::Delisle => ABSOLUTE_ZERO_CELSIUS + CELSIUS_BOILING,
+            _ => 0.0,
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    /// Formats the unit by its plural name, e.g. "cubic feet".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name(true))
+    }
 }
 
+/// The physical dimension measured by a [`Unit`].
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{Dimension, Unit};
+///
+/// let volumes: Vec<Unit> = Dimension::Volume.units().collect();
+/// assert_eq!(volumes.len(), 24);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dimension {
+    /// Temperature (Kelvin, Celsius, Fahrenheit, Rankine, and historical scales)
+    Temperature,
+    /// Volume (liters, tablespoons, cubic inches, cups, cubic feet, gallons, and more)
+    Volume,
+    /// Area (square meters, square feet, acres, hectares, square miles)
+    Area,
+    /// Length (meters, feet, miles, ...)
+    Length,
+    /// Mass (kilograms, grams, pounds, ...)
+    Mass,
+    /// Time (seconds, minutes, hours, days, weeks)
+    Time,
+    /// Energy (joules, calories, BTU, kilowatt hours, ...)
+    Energy,
+    /// Power (watts, kilowatts, horsepower)
+    Power,
+    /// Pressure (pascals, atmospheres, psi, mmHg, bars, ...)
+    Pressure,
+    /// Data size (bits, bytes, and decimal and binary multiples)
+    DataSize,
+}
+
+impl Dimension {
+    /// Returns every dimension, in the order units are declared.
+    #[must_use]
+    pub const fn all() -> &'static [Dimension] {
+        &[
+            Self::Temperature,
+            Self::Volume,
+            Self::Area,
+            Self::Length,
+            Self::Mass,
+            Self::Time,
+            Self::Energy,
+            Self::Power,
+            Self::Pressure,
+            Self::DataSize,
+        ]
+    }
+
+    /// Returns the units of this dimension, in declaration order.
+    pub fn units(self) -> impl Iterator<Item = Unit> {
+        Unit::all().iter().copied().filter(move |unit| unit.dimension() == self)
+    }
+
+    /// Returns the unit that [`Unit::scale`] and [`Unit::offset`] are
+    /// relative to: kelvins for temperature, liters for volume, square meters
+    /// for area, meters for length, kilograms for mass, seconds for time,
+    /// joules for energy, watts for power, pascals for pressure, and bytes for
+    /// data size.
+    #[must_use]
+    pub const fn base_unit(self) -> Unit {
+        match self {
+            Self::Temperature => Unit::Kelvin,
+            Self::Volume => Unit::Liters,
+            Self::Area => Unit::SquareMeters,
+            Self::Length => Unit::Meters,
+            Self::Mass => Unit::Kilograms,
+            Self::Time => Unit::Seconds,
+            Self::Energy => Unit::Joules,
+            Self::Power => Unit::Watts,
+            Self::Pressure => Unit::Pascals,
+            Self::DataSize => Unit::Bytes,
+        }
+    }
+
+    /// Returns the lowercase name of this dimension.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Temperature => "temperature",
+            Self::Volume => "volume",
+            Self::Area => "area",
+            Self::Length => "length",
+            Self::Mass => "mass",
+            Self::Time => "time",
+            Self::Energy => "energy",
+            Self::Power => "power",
+            Self::Pressure => "pressure",
+            Self::DataSize => "data size",
+        }
+    }
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Unit {
+    /// Parses a unit name, resolving bare names shared by the US customary
+    /// and Imperial systems (such as "gallon") in the given system.
+    ///
+    /// Text is read exactly as [`PrefixedUnit::from_str_with`] reads it, so
+    /// SI prefix symbols are case-sensitive: "mg" is milligrams, while "Mg" is
+    /// megagrams, which is not a built-in unit. A prefixed unit that is
+    /// built in, such as "Kg" for kilograms, parses as that unit.
+    /// [`Unit::from_str`](FromStr::from_str) does the same in
+    /// [`VolumeSystem::UsCustomary`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitParseError::UnknownUnit`] if the name is not recognized,
+    /// or [`UnitParseError::Prefixed`] if it names an SI-prefixed unit that
+    /// is not built in, along with the errors of
+    /// [`PrefixedUnit::from_str_with`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::{Unit, VolumeSystem};
+    ///
+    /// assert_eq!(Unit::from_str_with("gallon", VolumeSystem::Imperial), Ok(Unit::ImperialGallons));
+    /// assert_eq!(Unit::from_str_with("US gallon", VolumeSystem::Imperial), Ok(Unit::Gallons));
+    /// assert!(Unit::from_str_with("Mg", VolumeSystem::UsCustomary).is_err());
+    /// ```
+    pub fn from_str_with(s: &str, system: VolumeSystem) -> Result<Self, UnitParseError> {
+        let parsed = PrefixedUnit::from_str_with(s, system)?;
+        match (parsed.builtin(), parsed.prefix()) {
+            (Some(unit), _) => Ok(unit),
+            (None, Some(prefix)) => Err(UnitParseError::Prefixed { prefix, unit: parsed.unit() }),
+            (None, None) => Ok(parsed.unit()),
+        }
+    }
+}
+
+/// Parses a unit name, abbreviation, or symbol, resolving bare volume names
+/// such as "gallon" to the US customary measure.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::Unit;
+/// use std::str::FromStr;
+///
+/// // Parse from string (case-insensitive)
+/// let unit = Unit::from_str("celsius").unwrap();
+/// assert_eq!(unit, Unit::Celsius);
+///
+/// // Symbols, abbreviations, and singular forms are accepted too
+/// assert_eq!(Unit::from_str("°C").unwrap(), Unit::Celsius);
+/// assert_eq!(Unit::from_str("litre").unwrap(), Unit::Liters);
+/// ```
+#[cfg(feature = "std")]
 impl FromStr for Unit {
     type Err = UnitParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_uppercase().as_str() {
-            "KELVIN" => Ok(Self::Kelvin),
-            "CELSIUS" => Ok(Self::Celsius),
-            "FAHRENHEIT" => Ok(Self::Fahrenheit),
-            "RANKINE" => Ok(Self::Rankine),
-            "LITERS" => Ok(Self::Liters),
-            "TABLESPOONS" => Ok(Self::Tablespoons),
-            "CUBIC-INCHES" | "CUBICINCHES" => Ok(Self::CubicInches),
-            "CUPS" => Ok(Self::Cups),
-            "CUBIC-FEET" | "CUBICFEET" => Ok(Self::CubicFeet),
-            "GALLONS" => Ok(Self::Gallons),
-            _ => Err(UnitParseError::UnknownUnit(s.to_string())),
-        }
+        Self::from_str_with(s, VolumeSystem::UsCustomary)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Unit {
+    /// Serializes the unit by its plural name, e.g. "cubic feet".
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name(true))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Unit {
+    /// Accepts any name or alias that [`Unit::from_str`](FromStr::from_str)
+    /// does, so bare names shared by the US customary and Imperial systems
+    /// mean the US measure.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Self::from_str(&name).map_err(serde::de::Error::custom)
     }
 }
 
@@ -206,6 +1352,24 @@ impl FromStr for Unit {
 /// Temperature units can only be converted to other temperature units, and
 /// volume units can only be converted to other volume units.
 ///
+/// Pairs of the original worksheet units use the dedicated conversion
+/// functions (such as [`fah_kel`]) and their published constants. Any other
+/// pair of units with the same [`Dimension`] is converted through the
+/// dimension's base unit using [`Unit::scale`] and [`Unit::offset`].
+///
+/// The worksheet's volume constants are rounded (a gallon is 3.785 liters),
+/// while [`Unit::scale`] is exact, so volume conversions are not transitive:
+/// converting through another unit, or through a unit the worksheet did not
+/// cover, can give a slightly different answer than the direct pair.
+///
+/// ```rust
+/// use rustoleum::{Unit, convert};
+///
+/// // 1 gallon is 4 quarts, but only the gallon uses a worksheet constant
+/// assert_eq!(convert(Unit::Gallons, Unit::Liters, 1.0), Some(3.785));
+/// assert_eq!(convert(Unit::Quarts, Unit::Liters, 4.0), Some(3.785_411_784));
+/// ```
+///
 /// # Arguments
 ///
 /// * `from` - The source unit to convert from
@@ -230,6 +1394,10 @@ impl FromStr for Unit {
 /// let result = convert(Unit::Liters, Unit::Gallons, 1.0);
 /// assert_eq!(result, Some(0.2641));
 ///
+/// // Historical scales convert through kelvins
+/// let result = convert(Unit::Celsius, Unit::Reaumur, 100.0);
+/// assert_eq!(result, Some(80.0));
+///
 /// // Same unit returns the same value
 /// let result = convert(Unit::Celsius, Unit::Celsius, 100.0);
 /// assert_eq!(result, Some(100.0));
@@ -239,7 +1407,7 @@ impl FromStr for Unit {
 /// assert_eq!(result, None);
 /// ```
 #[must_use]
-pub fn convert(from: Unit, to: Unit, value: f64) -> Option<f64> {
+pub fn convert<T: Number>(from: Unit, to: Unit, value: T) -> Option<T> {
     if from == to {
         return Some(value);
     }
@@ -289,11 +1457,63 @@ pub fn convert(from: Unit, to: Unit, value: f64) -> Option<f64> {
         (Unit::Gallons, Unit::Tablespoons) => Some(gal_tab(value)),
         (Unit::Gallons, Unit::CubicFeet) => Some(gal_cf(value)),
         (Unit::Gallons, Unit::Cups) => Some(gal_cups(value)),
+        // Every other pair within a dimension goes through the base unit
+        _ if from.dimension() == to.dimension() => Some(via_base(from, to, value)),
         // Invalid conversions (temperature to volume or vice versa)
         _ => None,
     }
 }
 
+/// Converts between two units of the same dimension through the base unit.
+///
+/// Offsets are combined before scaling so that scales sharing a zero point
+/// (such as Celsius and Réaumur) convert by a single multiplication, and
+/// units without offsets convert by the single factor between their scales.
+pub(crate) fn via_base<T: Number>(from: Unit, to: Unit, value: T) -> T {
+    if from.offset() == 0.0 && to.offset() == 0.0 {
+        return value * (T::from_f64(from.scale()) / T::from_f64(to.scale()));
+    }
+    (value * T::from_f64(from.scale()) + T::from_f64(from.offset() - to.offset())) / T::from_f64(to.scale())
+}
+
+/// Returns the multiplication factor used for a volume conversion.
+pub(crate) const fn volume_factor(from: Unit, to: Unit) -> Option<f64> {
+    let factor = match (from, to) {
+        (Unit::Liters, Unit::Tablespoons) => LITERS_TO_TABLESPOONS,
+        (Unit::Liters, Unit::CubicInches) => LITERS_TO_CUBIC_INCHES,
+        (Unit::Liters, Unit::Cups) => LITERS_TO_CUPS,
+        (Unit::Liters, Unit::CubicFeet) => LITERS_TO_CUBIC_FEET,
+        (Unit::Liters, Unit::Gallons) => LITERS_TO_GALLONS,
+        (Unit::Tablespoons, Unit::Liters) => TABLESPOONS_TO_LITERS,
+        (Unit::Tablespoons, Unit::CubicInches) => TABLESPOONS_TO_CUBIC_INCHES,
+        (Unit::Tablespoons, Unit::Cups) => TABLESPOONS_TO_CUPS,
+        (Unit::Tablespoons, Unit::CubicFeet) => TABLESPOONS_TO_CUBIC_FEET,
+        (Unit::Tablespoons, Unit::Gallons) => TABLESPOONS_TO_GALLONS,
+        (Unit::CubicInches, Unit::Liters) => CUBIC_INCHES_TO_LITERS,
+        (Unit::CubicInches, Unit::Tablespoons) => CUBIC_INCHES_TO_TABLESPOONS,
+        (Unit::CubicInches, Unit::Cups) => CUBIC_INCHES_TO_CUPS,
+        (Unit::CubicInches, Unit::CubicFeet) => CUBIC_INCHES_TO_CUBIC_FEET,
+        (Unit::CubicInches, Unit::Gallons) => CUBIC_INCHES_TO_GALLONS,
+        (Unit::Cups, Unit::Liters) => CUPS_TO_LITERS,
+        (Unit::Cups, Unit::CubicInches) => CUPS_TO_CUBIC_INCHES,
+        (Unit::Cups, Unit::Tablespoons) => CUPS_TO_TABLESPOONS,
+        (Unit::Cups, Unit::CubicFeet) => CUPS_TO_CUBIC_FEET,
+        (Unit::Cups, Unit::Gallons) => CUPS_TO_GALLONS,
+        (Unit::CubicFeet, Unit::Liters) => CUBIC_FEET_TO_LITERS,
+        (Unit::CubicFeet, Unit::CubicInches) => CUBIC_FEET_TO_CUBIC_INCHES,
+        (Unit::CubicFeet, Unit::Tablespoons) => CUBIC_FEET_TO_TABLESPOONS,
+        (Unit::CubicFeet, Unit::Cups) => CUBIC_FEET_TO_CUPS,
+        (Unit::CubicFeet, Unit::Gallons) => CUBIC_FEET_TO_GALLONS,
+        (Unit::Gallons, Unit::Liters) => GALLONS_TO_LITERS,
+        (Unit::Gallons, Unit::CubicInches) => GALLONS_TO_CUBIC_INCHES,
+        (Unit::Gallons, Unit::Tablespoons) => GALLONS_TO_TABLESPOONS,
+        (Unit::Gallons, Unit::CubicFeet) => GALLONS_TO_CUBIC_FEET,
+        (Unit::Gallons, Unit::Cups) => GALLONS_TO_CUPS,
+        _ => return None,
+    };
+    Some(factor)
+}
+
 // Temperature conversions
 // ----------------------------------------
 
@@ -316,8 +1536,8 @@ pub fn convert(from: Unit, to: Unit, value: f64) -> Option<f64> {
 /// assert_eq!(celsius, 0.0);
 /// ```
 #[must_use]
-pub fn kel_cel(n: f64) -> f64 {
-    n - ABSOLUTE_ZERO_CELSIUS
+pub fn kel_cel<T: Number>(n: T) -> T {
+    n - T::from_f64(ABSOLUTE_ZERO_CELSIUS)
 }
 
 /// Converts Kelvin to Fahrenheit.
@@ -330,8 +1550,8 @@ pub fn kel_cel(n: f64) -> f64 {
 ///
 /// Temperature in Fahrenheit
 #[must_use]
-pub fn kel_fah(n: f64) -> f64 {
-    (n - ABSOLUTE_ZERO_CELSIUS) * CELSIUS_TO_FAHRENHEIT_RATIO + FAHRENHEIT_FREEZING
+pub fn kel_fah<T: Number>(n: T) -> T {
+    (n - T::from_f64(ABSOLUTE_ZERO_CELSIUS)) * T::from_f64(CELSIUS_TO_FAHRENHEIT_RATIO) + T::from_f64(FAHRENHEIT_FREEZING)
 }
 
 /// Converts Kelvin to Rankine.
@@ -344,8 +1564,8 @@ pub fn kel_fah(n: f64) -> f64 {
 ///
 /// Temperature in Rankine
 #[must_use]
-pub fn kel_ran(n: f64) -> f64 {
-    n * KELVIN_TO_RANKINE_RATIO
+pub fn kel_ran<T: Number>(n: T) -> T {
+    n * T::from_f64(KELVIN_TO_RANKINE_RATIO)
 }
 
 /// Converts Celsius to Kelvin.
@@ -367,8 +1587,8 @@ pub fn kel_ran(n: f64) -> f64 {
 /// assert_eq!(kelvin, 273.15);
 /// ```
 #[must_use]
-pub fn cel_kel(n: f64) -> f64 {
-    n + ABSOLUTE_ZERO_CELSIUS
+pub fn cel_kel<T: Number>(n: T) -> T {
+    n + T::from_f64(ABSOLUTE_ZERO_CELSIUS)
 }
 
 /// Converts Celsius to Fahrenheit.
@@ -390,8 +1610,8 @@ pub fn cel_kel(n: f64) -> f64 {
 /// assert_eq!(fahrenheit, 32.0);
 /// ```
 #[must_use]
-pub fn cel_fah(n: f64) -> f64 {
-    (n * CELSIUS_TO_FAHRENHEIT_RATIO) + FAHRENHEIT_FREEZING
+pub fn cel_fah<T: Number>(n: T) -> T {
+    (n * T::from_f64(CELSIUS_TO_FAHRENHEIT_RATIO)) + T::from_f64(FAHRENHEIT_FREEZING)
 }
 
 /// Converts Celsius to Rankine.
@@ -404,8 +1624,8 @@ pub fn cel_fah(n: f64) -> f64 {
 ///
 /// Temperature in Rankine
 #[must_use]
-pub fn cel_ran(n: f64) -> f64 {
-    (n * CELSIUS_TO_FAHRENHEIT_RATIO) + CELSIUS_TO_RANKINE_OFFSET
+pub fn cel_ran<T: Number>(n: T) -> T {
+    (n * T::from_f64(CELSIUS_TO_FAHRENHEIT_RATIO)) + T::from_f64(CELSIUS_TO_RANKINE_OFFSET)
 }
 
 /// Converts Fahrenheit to Kelvin.
@@ -418,8 +1638,8 @@ pub fn cel_ran(n: f64) -> f64 {
 ///
 /// Temperature in Kelvin
 #[must_use]
-pub fn fah_kel(n: f64) -> f64 {
-    (n - FAHRENHEIT_FREEZING) * FAHRENHEIT_TO_CELSIUS_RATIO + ABSOLUTE_ZERO_CELSIUS
+pub fn fah_kel<T: Number>(n: T) -> T {
+    (n - T::from_f64(FAHRENHEIT_FREEZING)) * T::from_f64(FAHRENHEIT_TO_CELSIUS_RATIO) + T::from_f64(ABSOLUTE_ZERO_CELSIUS)
 }
 
 /// Converts Fahrenheit to Celsius.
@@ -441,8 +1661,8 @@ pub fn fah_kel(n: f64) -> f64 {
 /// assert_eq!(celsius, 0.0);
 /// ```
 #[must_use]
-pub fn fah_cel(n: f64) -> f64 {
-    (n - FAHRENHEIT_FREEZING) * FAHRENHEIT_TO_CELSIUS_RATIO
+pub fn fah_cel<T: Number>(n: T) -> T {
+    (n - T::from_f64(FAHRENHEIT_FREEZING)) * T::from_f64(FAHRENHEIT_TO_CELSIUS_RATIO)
 }
 
 /// Converts Fahrenheit to Rankine.
@@ -455,8 +1675,8 @@ pub fn fah_cel(n: f64) -> f64 {
 ///
 /// Temperature in Rankine
 #[must_use]
-pub fn fah_ran(n: f64) -> f64 {
-    n + FAHRENHEIT_TO_RANKINE_OFFSET
+pub fn fah_ran<T: Number>(n: T) -> T {
+    n + T::from_f64(FAHRENHEIT_TO_RANKINE_OFFSET)
 }
 
 /// Converts Rankine to Kelvin.
@@ -469,8 +1689,8 @@ pub fn fah_ran(n: f64) -> f64 {
 ///
 /// Temperature in Kelvin
 #[must_use]
-pub fn ran_kel(n: f64) -> f64 {
-    n * RANKINE_TO_KELVIN_RATIO
+pub fn ran_kel<T: Number>(n: T) -> T {
+    n * T::from_f64(RANKINE_TO_KELVIN_RATIO)
 }
 
 /// Converts Rankine to Celsius.
@@ -483,8 +1703,8 @@ pub fn ran_kel(n: f64) -> f64 {
 ///
 /// Temperature in Celsius
 #[must_use]
-pub fn ran_cel(n: f64) -> f64 {
-    (n * RANKINE_TO_KELVIN_RATIO) - ABSOLUTE_ZERO_CELSIUS
+pub fn ran_cel<T: Number>(n: T) -> T {
+    (n * T::from_f64(RANKINE_TO_KELVIN_RATIO)) - T::from_f64(ABSOLUTE_ZERO_CELSIUS)
 }
 
 /// Converts Rankine to Fahrenheit.
@@ -497,8 +1717,8 @@ pub fn ran_cel(n: f64) -> f64 {
 ///
 /// Temperature in Fahrenheit
 #[must_use]
-pub fn ran_fah(n: f64) -> f64 {
-    n - FAHRENHEIT_TO_RANKINE_OFFSET
+pub fn ran_fah<T: Number>(n: T) -> T {
+    n - T::from_f64(FAHRENHEIT_TO_RANKINE_OFFSET)
 }
 
 // Volume conversions
@@ -514,8 +1734,8 @@ pub fn ran_fah(n: f64) -> f64 {
 ///
 /// Volume in tablespoons
 #[must_use]
-pub fn lit_tab(n: f64) -> f64 {
-    n * LITERS_TO_TABLESPOONS
+pub fn lit_tab<T: Number>(n: T) -> T {
+    n * T::from_f64(LITERS_TO_TABLESPOONS)
 }
 
 /// Converts Liters to Cubic Inches.
@@ -528,8 +1748,8 @@ pub fn lit_tab(n: f64) -> f64 {
 ///
 /// Volume in cubic inches
 #[must_use]
-pub fn lit_ci(n: f64) -> f64 {
-    n * LITERS_TO_CUBIC_INCHES
+pub fn lit_ci<T: Number>(n: T) -> T {
+    n * T::from_f64(LITERS_TO_CUBIC_INCHES)
 }
 
 /// Converts Liters to Cups.
@@ -542,8 +1762,8 @@ pub fn lit_ci(n: f64) -> f64 {
 ///
 /// Volume in cups
 #[must_use]
-pub fn lit_cups(n: f64) -> f64 {
-    n * LITERS_TO_CUPS
+pub fn lit_cups<T: Number>(n: T) -> T {
+    n * T::from_f64(LITERS_TO_CUPS)
 }
 
 /// Converts Liters to Cubic Feet.
@@ -556,8 +1776,8 @@ pub fn lit_cups(n: f64) -> f64 {
 ///
 /// Volume in cubic feet
 #[must_use]
-pub fn lit_cf(n: f64) -> f64 {
-    n * LITERS_TO_CUBIC_FEET
+pub fn lit_cf<T: Number>(n: T) -> T {
+    n * T::from_f64(LITERS_TO_CUBIC_FEET)
 }
 
 /// Converts Liters to Gallons.
@@ -570,8 +1790,8 @@ pub fn lit_cf(n: f64) -> f64 {
 ///
 /// Volume in gallons
 #[must_use]
-pub fn lit_gal(n: f64) -> f64 {
-    n * LITERS_TO_GALLONS
+pub fn lit_gal<T: Number>(n: T) -> T {
+    n * T::from_f64(LITERS_TO_GALLONS)
 }
 
 /// Converts Tablespoons to Liters.
@@ -584,8 +1804,8 @@ pub fn lit_gal(n: f64) -> f64 {
 ///
 /// Volume in liters
 #[must_use]
-pub fn tab_lit(n: f64) -> f64 {
-    n * TABLESPOONS_TO_LITERS
+pub fn tab_lit<T: Number>(n: T) -> T {
+    n * T::from_f64(TABLESPOONS_TO_LITERS)
 }
 
 /// Converts Tablespoons to Cubic Inches.
@@ -598,8 +1818,8 @@ pub fn tab_lit(n: f64) -> f64 {
 ///
 /// Volume in cubic inches
 #[must_use]
-pub fn tab_ci(n: f64) -> f64 {
-    n * TABLESPOONS_TO_CUBIC_INCHES
+pub fn tab_ci<T: Number>(n: T) -> T {
+    n * T::from_f64(TABLESPOONS_TO_CUBIC_INCHES)
 }
 
 /// Converts Tablespoons to Cups.
@@ -612,8 +1832,8 @@ pub fn tab_ci(n: f64) -> f64 {
 ///
 /// Volume in cups
 #[must_use]
-pub fn tab_cups(n: f64) -> f64 {
-    n * TABLESPOONS_TO_CUPS
+pub fn tab_cups<T: Number>(n: T) -> T {
+    n * T::from_f64(TABLESPOONS_TO_CUPS)
 }
 
 /// Converts Tablespoons to Cubic Feet.
@@ -626,8 +1846,8 @@ pub fn tab_cups(n: f64) -> f64 {
 ///
 /// Volume in cubic feet
 #[must_use]
-pub fn tab_cf(n: f64) -> f64 {
-    n * TABLESPOONS_TO_CUBIC_FEET
+pub fn tab_cf<T: Number>(n: T) -> T {
+    n * T::from_f64(TABLESPOONS_TO_CUBIC_FEET)
 }
 
 /// Converts Tablespoons to Gallons.
@@ -640,8 +1860,8 @@ pub fn tab_cf(n: f64) -> f64 {
 ///
 /// Volume in gallons
 #[must_use]
-pub fn tab_gal(n: f64) -> f64 {
-    n * TABLESPOONS_TO_GALLONS
+pub fn tab_gal<T: Number>(n: T) -> T {
+    n * T::from_f64(TABLESPOONS_TO_GALLONS)
 }
 
 /// Converts Cubic Inches to Liters.
@@ -654,8 +1874,8 @@ pub fn tab_gal(n: f64) -> f64 {
 ///
 /// Volume in liters
 #[must_use]
-pub fn ci_lit(n: f64) -> f64 {
-    n * CUBIC_INCHES_TO_LITERS
+pub fn ci_lit<T: Number>(n: T) -> T {
+    n * T::from_f64(CUBIC_INCHES_TO_LITERS)
 }
 
 /// Converts Cubic Inches to Tablespoons.
@@ -668,8 +1888,8 @@ pub fn ci_lit(n: f64) -> f64 {
 ///
 /// Volume in tablespoons
 #[must_use]
-pub fn ci_tab(n: f64) -> f64 {
-    n * CUBIC_INCHES_TO_TABLESPOONS
+pub fn ci_tab<T: Number>(n: T) -> T {
+    n * T::from_f64(CUBIC_INCHES_TO_TABLESPOONS)
 }
 
 /// Converts Cubic Inches to Cups.
@@ -682,8 +1902,8 @@ pub fn ci_tab(n: f64) -> f64 {
 ///
 /// Volume in cups
 #[must_use]
-pub fn ci_cups(n: f64) -> f64 {
-    n * CUBIC_INCHES_TO_CUPS
+pub fn ci_cups<T: Number>(n: T) -> T {
+    n * T::from_f64(CUBIC_INCHES_TO_CUPS)
 }
 
 /// Converts Cubic Inches to Cubic Feet.
@@ -696,8 +1916,8 @@ pub fn ci_cups(n: f64) -> f64 {
 ///
 /// Volume in cubic feet
 #[must_use]
-pub fn ci_cf(n: f64) -> f64 {
-    n * CUBIC_INCHES_TO_CUBIC_FEET
+pub fn ci_cf<T: Number>(n: T) -> T {
+    n * T::from_f64(CUBIC_INCHES_TO_CUBIC_FEET)
 }
 
 /// Converts Cubic Inches to Gallons.
@@ -710,8 +1930,8 @@ pub fn ci_cf(n: f64) -> f64 {
 ///
 /// Volume in gallons
 #[must_use]
-pub fn ci_gal(n: f64) -> f64 {
-    n * CUBIC_INCHES_TO_GALLONS
+pub fn ci_gal<T: Number>(n: T) -> T {
+    n * T::from_f64(CUBIC_INCHES_TO_GALLONS)
 }
 
 /// Converts Cups to Liters.
@@ -724,8 +1944,8 @@ pub fn ci_gal(n: f64) -> f64 {
 ///
 /// Volume in liters
 #[must_use]
-pub fn cups_lit(n: f64) -> f64 {
-    n * CUPS_TO_LITERS
+pub fn cups_lit<T: Number>(n: T) -> T {
+    n * T::from_f64(CUPS_TO_LITERS)
 }
 
 /// Converts Cups to Cubic Inches.
@@ -738,8 +1958,8 @@ pub fn cups_lit(n: f64) -> f64 {
 ///
 /// Volume in cubic inches
 #[must_use]
-pub fn cups_ci(n: f64) -> f64 {
-    n * CUPS_TO_CUBIC_INCHES
+pub fn cups_ci<T: Number>(n: T) -> T {
+    n * T::from_f64(CUPS_TO_CUBIC_INCHES)
 }
 
 /// Converts Cups to Tablespoons.
@@ -752,8 +1972,8 @@ pub fn cups_ci(n: f64) -> f64 {
 ///
 /// Volume in tablespoons
 #[must_use]
-pub fn cups_tab(n: f64) -> f64 {
-    n * CUPS_TO_TABLESPOONS
+pub fn cups_tab<T: Number>(n: T) -> T {
+    n * T::from_f64(CUPS_TO_TABLESPOONS)
 }
 
 /// Converts Cups to Cubic Feet.
@@ -766,8 +1986,8 @@ pub fn cups_tab(n: f64) -> f64 {
 ///
 /// Volume in cubic feet
 #[must_use]
-pub fn cups_cf(n: f64) -> f64 {
-    n * CUPS_TO_CUBIC_FEET
+pub fn cups_cf<T: Number>(n: T) -> T {
+    n * T::from_f64(CUPS_TO_CUBIC_FEET)
 }
 
 /// Converts Cups to Gallons.
@@ -780,8 +2000,8 @@ pub fn cups_cf(n: f64) -> f64 {
 ///
 /// Volume in gallons
 #[must_use]
-pub fn cups_gal(n: f64) -> f64 {
-    n * CUPS_TO_GALLONS
+pub fn cups_gal<T: Number>(n: T) -> T {
+    n * T::from_f64(CUPS_TO_GALLONS)
 }
 
 /// Converts Cubic Feet to Liters.
@@ -794,8 +2014,8 @@ pub fn cups_gal(n: f64) -> f64 {
 ///
 /// Volume in liters
 #[must_use]
-pub fn cf_lit(n: f64) -> f64 {
-    n * CUBIC_FEET_TO_LITERS
+pub fn cf_lit<T: Number>(n: T) -> T {
+    n * T::from_f64(CUBIC_FEET_TO_LITERS)
 }
 
 /// Converts Cubic Feet to Cubic Inches.
@@ -808,8 +2028,8 @@ pub fn cf_lit(n: f64) -> f64 {
 ///
 /// Volume in cubic inches
 #[must_use]
-pub fn cf_ci(n: f64) -> f64 {
-    n * CUBIC_FEET_TO_CUBIC_INCHES
+pub fn cf_ci<T: Number>(n: T) -> T {
+    n * T::from_f64(CUBIC_FEET_TO_CUBIC_INCHES)
 }
 
 /// Converts Cubic Feet to Tablespoons.
@@ -822,8 +2042,8 @@ pub fn cf_ci(n: f64) -> f64 {
 ///
 /// Volume in tablespoons
 #[must_use]
-pub fn cf_tab(n: f64) -> f64 {
-    n * CUBIC_FEET_TO_TABLESPOONS
+pub fn cf_tab<T: Number>(n: T) -> T {
+    n * T::from_f64(CUBIC_FEET_TO_TABLESPOONS)
 }
 
 /// Converts Cubic Feet to Cups.
@@ -836,8 +2056,8 @@ pub fn cf_tab(n: f64) -> f64 {
 ///
 /// Volume in cups
 #[must_use]
-pub fn cf_cups(n: f64) -> f64 {
-    n * CUBIC_FEET_TO_CUPS
+pub fn cf_cups<T: Number>(n: T) -> T {
+    n * T::from_f64(CUBIC_FEET_TO_CUPS)
 }
 
 /// Converts Cubic Feet to Gallons.
@@ -850,8 +2070,8 @@ pub fn cf_cups(n: f64) -> f64 {
 ///
 /// Volume in gallons
 #[must_use]
-pub fn cf_gal(n: f64) -> f64 {
-    n * CUBIC_FEET_TO_GALLONS
+pub fn cf_gal<T: Number>(n: T) -> T {
+    n * T::from_f64(CUBIC_FEET_TO_GALLONS)
 }
 
 /// Converts Gallons to Liters.
@@ -864,8 +2084,8 @@ pub fn cf_gal(n: f64) -> f64 {
 ///
 /// Volume in liters
 #[must_use]
-pub fn gal_lit(n: f64) -> f64 {
-    n * GALLONS_TO_LITERS
+pub fn gal_lit<T: Number>(n: T) -> T {
+    n * T::from_f64(GALLONS_TO_LITERS)
 }
 
 /// Converts Gallons to Cubic Inches.
@@ -878,8 +2098,8 @@ pub fn gal_lit(n: f64) -> f64 {
 ///
 /// Volume in cubic inches
 #[must_use]
-pub fn gal_ci(n: f64) -> f64 {
-    n * GALLONS_TO_CUBIC_INCHES
+pub fn gal_ci<T: Number>(n: T) -> T {
+    n * T::from_f64(GALLONS_TO_CUBIC_INCHES)
 }
 
 /// Converts Gallons to Tablespoons.
@@ -892,8 +2112,8 @@ pub fn gal_ci(n: f64) -> f64 {
 ///
 /// Volume in tablespoons
 #[must_use]
-pub fn gal_tab(n: f64) -> f64 {
-    n * GALLONS_TO_TABLESPOONS
+pub fn gal_tab<T: Number>(n: T) -> T {
+    n * T::from_f64(GALLONS_TO_TABLESPOONS)
 }
 
 /// Converts Gallons to Cubic Feet.
@@ -906,8 +2126,8 @@ pub fn gal_tab(n: f64) -> f64 {
 ///
 /// Volume in cubic feet
 #[must_use]
-pub fn gal_cf(n: f64) -> f64 {
-    n * GALLONS_TO_CUBIC_FEET
+pub fn gal_cf<T: Number>(n: T) -> T {
+    n * T::from_f64(GALLONS_TO_CUBIC_FEET)
 }
 
 /// Converts Gallons to Cups.
@@ -920,13 +2140,13 @@ pub fn gal_cf(n: f64) -> f64 {
 ///
 /// Volume in cups
 #[must_use]
-pub fn gal_cups(n: f64) -> f64 {
-    n * GALLONS_TO_CUPS
+pub fn gal_cups<T: Number>(n: T) -> T {
+    n * T::from_f64(GALLONS_TO_CUPS)
 }
 
 
 // Unit tests go here
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     use float_cmp::*;
@@ -1363,6 +2583,288 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    // test every unit belongs to exactly one dimension's listing
+    fn test_dimension_units() {
+        let listed: usize = Dimension::all().iter().map(|d| d.units().count()).sum();
+        assert_eq!(listed, Unit::all().len());
+        for dimension in Dimension::all() {
+            for unit in dimension.units() {
+                assert_eq!(unit.dimension(), *dimension);
+            }
+        }
+    }
+
+    #[test]
+    // test conversions are valid exactly within a dimension
+    fn test_dimension_matches_convert() {
+        for &from in Unit::all() {
+            for &to in Unit::all() {
+                let same = from.dimension() == to.dimension();
+                assert_eq!(convert(from, to, 1.0).is_some(), same, "{from:?} -> {to:?}");
+            }
+        }
+    }
+
+    #[test]
+    // test symbols, names, and Display round-trip through from_str
+    fn test_unit_metadata_round_trip() {
+        for &unit in Unit::all() {
+            assert_eq!(Unit::from_str(unit.symbol()), Ok(unit));
+            assert_eq!(Unit::from_str(unit.name(false)), Ok(unit));
+            assert_eq!(Unit::from_str(unit.name(true)), Ok(unit));
+            assert_eq!(Unit::from_str(&unit.to_string()), Ok(unit));
+        }
+    }
+
+    #[test]
+    // test historical temperature scales at the fixed points of water
+    fn test_historical_temperature_scales() {
+        let boiling = [
+            (Unit::Reaumur, 80.0),
+            (Unit::Romer, 60.0),
+            (Unit::Delisle, 0.0),
+            (Unit::Newton, 33.0),
+        ];
+        let freezing = [
+            (Unit::Reaumur, 0.0),
+            (Unit::Romer, 7.5),
+            (Unit::Delisle, 150.0),
+            (Unit::Newton, 0.0),
+        ];
+        for (unit, expected) in boiling {
+            let result = convert(Unit::Celsius, unit, 100.0).unwrap();
+            assert!(approx_eq!(f64, result, expected, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{unit:?}");
+            let result = convert(Unit::Fahrenheit, unit, 212.0).unwrap();
+            assert!(approx_eq!(f64, result, expected, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{unit:?}");
+        }
+        for (unit, expected) in freezing {
+            let result = convert(Unit::Kelvin, unit, 273.15).unwrap();
+            assert!(approx_eq!(f64, result, expected, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{unit:?}");
+            let result = convert(unit, Unit::Rankine, expected).unwrap();
+            assert!(approx_eq!(f64, result, 491.67, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{unit:?}");
+        }
+    }
+
+    #[test]
+    // test the extended volume units against their customary relationships
+    fn test_extended_volume_units() {
+        let cases = [
+            (Unit::Teaspoons, Unit::Tablespoons, 3.0, 1.0),
+            (Unit::FluidOunces, Unit::Tablespoons, 1.0, 2.0),
+            (Unit::Cups, Unit::FluidOunces, 1.0, 8.0),
+            (Unit::Pints, Unit::Cups, 1.0, 2.0),
+            (Unit::Quarts, Unit::Pints, 1.0, 2.0),
+            (Unit::Gallons, Unit::Quarts, 1.0, 4.0),
+            (Unit::Liters, Unit::Milliliters, 1.0, 1000.0),
+            (Unit::CubicMeters, Unit::Liters, 1.0, 1000.0),
+            (Unit::Barrels, Unit::Gallons, 1.0, 42.0),
+            (Unit::Quarts, Unit::CubicInches, 1.0, 57.75),
+        ];
+        for (from, to, value, expected) in cases {
+            let result = convert(from, to, value).unwrap();
+            assert!(approx_eq!(f64, result, expected, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{from:?} -> {to:?}: {result}");
+        }
+        // The worksheet pairs keep their published constants
+        assert_eq!(convert(Unit::Liters, Unit::Gallons, 1.0), Some(0.2641));
+    }
+
+    #[test]
+    // test the rounded worksheet constants make volume conversions
+    // intransitive, as documented on convert
+    fn test_volume_constants_not_transitive() {
+        assert_eq!(convert(Unit::Gallons, Unit::Liters, 1.0), Some(3.785));
+        assert_eq!(convert(Unit::Quarts, Unit::Liters, 4.0), Some(3.785_411_784));
+        let via_quarts = convert(Unit::Gallons, Unit::Quarts, 1.0).and_then(|quarts| convert(Unit::Quarts, Unit::Liters, quarts));
+        assert_ne!(via_quarts, convert(Unit::Gallons, Unit::Liters, 1.0));
+        let direct = factor_label(Unit::Gallons, Unit::Liters, 1.0).unwrap();
+        let chained = factor_label_via(Unit::Gallons, &[Unit::Quarts], Unit::Liters, 1.0).unwrap();
+        assert_eq!(direct.result(), 3.785);
+        assert_ne!(chained.result(), direct.result());
+    }
+
+    #[test]
+    // test Imperial and US dry measures
+    fn test_imperial_and_dry_volume_units() {
+        let cases = [
+            (Unit::ImperialGallons, Unit::Gallons, 1.0, 1.20095),
+            (Unit::ImperialGallons, Unit::ImperialPints, 1.0, 8.0),
+            (Unit::ImperialPints, Unit::ImperialFluidOunces, 1.0, 20.0),
+            (Unit::ImperialPints, Unit::Pints, 1.0, 1.20095),
+            (Unit::ImperialCups, Unit::Milliliters, 1.0, 284.130625),
+            (Unit::ImperialFluidOunces, Unit::ImperialTablespoons, 5.0, 8.0),
+            (Unit::Bushels, Unit::Pecks, 1.0, 4.0),
+            (Unit::Pecks, Unit::DryGallons, 1.0, 2.0),
+            (Unit::DryQuarts, Unit::DryPints, 1.0, 2.0),
+            (Unit::Bushels, Unit::CubicInches, 1.0, 2150.42),
+        ];
+        for (from, to, value, expected) in cases {
+            let result = convert(from, to, value).unwrap();
+            assert!(approx_eq!(f64, result, expected, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{from:?} -> {to:?}: {result}");
+        }
+    }
+
+    #[test]
+    // test area conversions
+    fn test_area_units() {
+        let cases = [
+            (Unit::SquareMeters, Unit::SquareFeet, 1.0, 10.7639),
+            (Unit::Acres, Unit::SquareFeet, 1.0, 43560.0),
+            (Unit::SquareMiles, Unit::Acres, 1.0, 640.0),
+            (Unit::Hectares, Unit::Acres, 1.0, 2.47105),
+            (Unit::SquareMiles, Unit::Hectares, 1.0, 258.9988),
+        ];
+        for (from, to, value, expected) in cases {
+            let result = convert(from, to, value).unwrap();
+            assert!(approx_eq!(f64, result, expected, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{from:?} -> {to:?}: {result}");
+        }
+        assert_eq!(convert(Unit::Acres, Unit::Liters, 1.0), None);
+    }
+
+    #[test]
+    // test area and volume units share one length definition
+    fn test_length_derived_units() {
+        let foot = METERS_PER_FOOT;
+        assert_eq!(Unit::SquareFeet.scale(), foot * foot);
+        assert_eq!(Unit::CubicFeet.scale(), foot * foot * foot * LITERS_PER_CUBIC_METER);
+        let cubic_inches = convert(Unit::CubicFeet, Unit::CubicInches, 1.0).unwrap();
+        assert!(approx_eq!(f64, cubic_inches, 1728.0, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+        let square_feet_per_square_meter = 1.0 / Unit::SquareFeet.scale();
+        let cubic_feet_per_cubic_meter = 1.0 / convert(Unit::CubicFeet, Unit::CubicMeters, 1.0).unwrap();
+        assert!(approx_eq!(f64, square_feet_per_square_meter.powf(1.5), cubic_feet_per_cubic_meter, epsilon = 1e-9));
+        assert_eq!(Unit::Gallons.scale(), 231.0 * Unit::CubicInches.scale());
+    }
+
+    #[test]
+    // test energy, power, and pressure conversions
+    fn test_energy_power_pressure_units() {
+        let cases = [
+            (Unit::Kilocalories, Unit::Joules, 1.0, 4184.0),
+            (Unit::Calories, Unit::Joules, 1.0, 4.184),
+            (Unit::Btu, Unit::Kilojoules, 1.0, 1.05506),
+            (Unit::KilowattHours, Unit::Btu, 1.0, 3412.1416),
+            (Unit::Horsepower, Unit::Watts, 1.0, 745.6999),
+            (Unit::Kilowatts, Unit::Horsepower, 1.0, 1.34102),
+            (Unit::Atmospheres, Unit::Psi, 1.0, 14.69595),
+            (Unit::Atmospheres, Unit::MillimetersOfMercury, 1.0, 760.0),
+            (Unit::Bars, Unit::Kilopascals, 1.0, 100.0),
+            (Unit::Psi, Unit::Pascals, 1.0, 6894.7573),
+        ];
+        for (from, to, value, expected) in cases {
+            let result = convert(from, to, value).unwrap();
+            assert!(approx_eq!(f64, result, expected, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{from:?} -> {to:?}: {result}");
+        }
+        assert_eq!(convert(Unit::Watts, Unit::Joules, 1.0), None);
+    }
+
+    #[test]
+    // test time and data-size conversions, including decimal vs. binary prefixes
+    fn test_time_and_data_size_units() {
+        let cases = [
+            (Unit::Weeks, Unit::Days, 1.0, 7.0),
+            (Unit::Days, Unit::Minutes, 1.0, 1440.0),
+            (Unit::Hours, Unit::Seconds, 2.5, 9000.0),
+            (Unit::Bytes, Unit::Bits, 1.0, 8.0),
+            (Unit::Kilobytes, Unit::Bytes, 1.0, 1000.0),
+            (Unit::Kibibytes, Unit::Bytes, 1.0, 1024.0),
+            (Unit::Mebibytes, Unit::Kibibytes, 1.0, 1024.0),
+            (Unit::Gigabytes, Unit::Gibibytes, 1.0, 0.9313),
+            (Unit::Tebibytes, Unit::Terabytes, 1.0, 1.0995),
+            (Unit::Megabytes, Unit::Mebibytes, 500.0, 476.8372),
+        ];
+        for (from, to, value, expected) in cases {
+            let result = convert(from, to, value).unwrap();
+            assert!(approx_eq!(f64, result, expected, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{from:?} -> {to:?}: {result}");
+        }
+        assert_eq!(convert(Unit::Bytes, Unit::Seconds, 1.0), None);
+    }
+
+    #[test]
+    // test every parser reads SI prefix symbols case-sensitively and alike
+    fn test_parse_prefix_case_agrees() {
+        assert_eq!(Unit::from_str("mg"), Ok(Unit::Milligrams));
+        assert_eq!(Unit::from_str("mL"), Ok(Unit::Milliliters));
+        assert_eq!(Unit::from_str("kg"), Ok(Unit::Kilograms));
+        let registry = UnitRegistry::new();
+        for (text, prefix, unit, expected) in [
+            ("Mg", Prefix::Mega, Unit::Grams, 1e6),
+            ("ML", Prefix::Mega, Unit::Liters, 1e6),
+            ("kL", Prefix::Kilo, Unit::Liters, 1e3),
+        ] {
+            assert_eq!(Unit::from_str(text), Err(UnitParseError::Prefixed { prefix, unit }));
+            assert_eq!(PrefixedUnit::from_str(text), PrefixedUnit::new(prefix, unit));
+            let evaluated = evaluate(&format!("1 {text} in {}", unit.symbol())).unwrap();
+            assert_eq!(evaluated, Quantity::new(expected, unit), "{text}");
+            assert_eq!(registry.convert(text, unit.symbol(), 1.0), Ok(expected), "{text}");
+        }
+        assert_eq!(
+            Unit::from_str("Mg").unwrap_err().to_string(),
+            "Unit 'Mg' is megagrams, which is not a built-in unit"
+        );
+        assert_eq!(
+            evaluate("1 L in kL"),
+            Err(ExprError::UnsupportedOperation("converting to an SI-prefixed unit"))
+        );
+    }
+
+    #[test]
+    // test lower-case "b" byte symbols are rejected as ambiguous with bits
+    fn test_parse_ambiguous_data_size() {
+        assert_eq!(Unit::from_str("MB"), Ok(Unit::Megabytes));
+        assert_eq!(Unit::from_str("kB"), Ok(Unit::Kilobytes));
+        assert_eq!(Unit::from_str("mbyte"), Ok(Unit::Megabytes));
+        assert_eq!(Unit::from_str("bytes"), Ok(Unit::Bytes));
+        for (text, unit) in [("b", Unit::Bytes), ("Mb", Unit::Megabytes), ("kb", Unit::Kilobytes), ("gib", Unit::Gibibytes)] {
+            assert_eq!(Unit::from_str(text), Err(UnitParseError::AmbiguousDataSize { text: text.to_string(), unit }));
+            assert_eq!(
+                PrefixedUnit::from_str(text),
+                Err(UnitParseError::AmbiguousDataSize { text: text.to_string(), unit })
+            );
+        }
+        assert_eq!(
+            Unit::from_str("Mb").unwrap_err().to_string(),
+            "Ambiguous unit 'Mb': write 'MB' for megabytes or 'bit' for bits"
+        );
+        assert_eq!(PrefixedUnit::from_str("Mbit"), PrefixedUnit::new(Prefix::Mega, Unit::Bits));
+    }
+
+    #[test]
+    // test round-trips among every pair of temperature scales
+    fn test_roundtrip_all_temperatures() {
+        for from in Dimension::Temperature.units() {
+            for to in Dimension::Temperature.units() {
+                let there = convert(from, to, 36.6).unwrap();
+                let back = convert(to, from, there).unwrap();
+                assert!(
+                    approx_eq!(f64, back, 36.6, (ROUNDTRIP_TOLERANCE_EPSILON, ROUNDTRIP_TOLERANCE_ULPS)),
+                    "{from:?} -> {to:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    // test exact unit definitions agree with the worksheet conversions
+    fn test_unit_scale_offset() {
+        for &from in Unit::all() {
+            for &to in Unit::all() {
+                let Some(expected) = convert(from, to, 10.0) else {
+                    continue;
+                };
+                let base = 10.0 * from.scale() + from.offset();
+                let exact = (base - to.offset()) / to.scale();
+                assert!(
+                    approx_eq!(f64, exact, expected, (ROUNDTRIP_TOLERANCE_EPSILON * expected.abs().max(1.0), ROUNDTRIP_TOLERANCE_ULPS)),
+                    "{from:?} -> {to:?}: {exact} vs {expected}"
+                );
+            }
+        }
+        for dimension in Dimension::all() {
+            let base = dimension.base_unit();
+            assert_eq!((base.scale(), base.offset()), (1.0, 0.0));
+        }
+    }
+
     #[test]
     // test Unit::from_str parsing
     fn test_unit_from_str() {
@@ -1377,4 +2879,41 @@ mod tests {
             Err(UnitParseError::UnknownUnit("invalid".to_string()))
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    // test units serialize by name and deserialize from any alias
+    fn test_unit_serde() {
+        for &unit in Unit::all() {
+            let json = serde_json::to_string(&unit).unwrap();
+            assert_eq!(json, format!("\"{}\"", unit.name(true)));
+            assert_eq!(serde_json::from_str::<Unit>(&json).unwrap(), unit, "{json}");
+        }
+        assert_eq!(serde_json::from_str::<Unit>("\"ft³\"").unwrap(), Unit::CubicFeet);
+        assert_eq!(serde_json::from_str::<Unit>("\"gallons\"").unwrap(), Unit::Gallons);
+        assert_eq!(serde_json::from_str::<Unit>("\"imp gal\"").unwrap(), Unit::ImperialGallons);
+        let err = serde_json::from_str::<Unit>("\"farenheit\"").unwrap_err();
+        assert!(err.to_string().starts_with("Unknown unit: 'farenheit'"), "{err}");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    // test parse errors round-trip through serde
+    fn test_unit_parse_error_serde() {
+        let errors = [
+            UnitParseError::UnknownUnit("farenheit".to_string()),
+            UnitParseError::PrefixNotAllowed { prefix: Prefix::Kilo, unit: Unit::Gallons },
+        ];
+        let json = serde_json::to_string(&errors).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"unknown_unit":"farenheit"},{"prefix_not_allowed":{"prefix":"kilo","unit":"gallons"}}]"#
+        );
+        assert_eq!(serde_json::from_str::<Vec<UnitParseError>>(&json).unwrap(), errors);
+        let symbol = r#"{"prefix_not_allowed":{"prefix":"µ","unit":"gal"}}"#;
+        assert_eq!(
+            serde_json::from_str::<UnitParseError>(symbol).unwrap(),
+            UnitParseError::PrefixNotAllowed { prefix: Prefix::Micro, unit: Unit::Gallons }
+        );
+    }
 }
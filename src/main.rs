@@ -1,25 +1,195 @@
 use std::env;
 use std::process::ExitCode;
 use std::str::FromStr;
-use rustoleum::{Unit, convert, TOLERANCE_EPSILON, TOLERANCE_ULPS};
-use float_cmp::approx_eq;
+use rustoleum::{CompoundUnit, CompoundUnitError, Decimal, Dimension, PrefixedUnit, RegistryError, Substance, Unit, UnitParseError, UnitRegistry, VolumeSystem, convert, convert_substance, evaluate_with, ExprError, explain, explain_substance, grade, parse_answer, Format};
 
 fn main() -> ExitCode {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+
+    // Pull out option flags so the positional arguments stay in place
+    let mut explain_format = None;
+    let mut units_file = None;
+    let mut substance = None;
+    let mut volume_system = VolumeSystem::UsCustomary;
+    let mut flags = Vec::new();
+    args.retain(|arg| {
+        if arg.starts_with("--") {
+            flags.push(arg.clone());
+            false
+        } else {
+            true
+        }
+    });
+    for flag in &flags {
+        if flag == "--explain" {
+            explain_format = Some(Format::Text);
+        } else if let Some(format) = flag.strip_prefix("--explain=") {
+            let Ok(format) = Format::from_str(format) else {
+                eprintln!("{} : unknown explanation format '{format}' (expected text, markdown, or latex)", &args[0]);
+                return ExitCode::from(1);
+            };
+            explain_format = Some(format);
+        } else if let Some(path) = flag.strip_prefix("--units-file=") {
+            units_file = Some(path.to_string());
+        } else if let Some(system) = flag.strip_prefix("--volume-system=") {
+            let Ok(system) = VolumeSystem::from_str(system) else {
+                eprintln!("{} : unknown volume system '{system}' (expected us or imperial)", &args[0]);
+                return ExitCode::from(1);
+            };
+            volume_system = system;
+        } else if let Some(name) = flag.strip_prefix("--substance=") {
+            match Substance::from_str(name) {
+                Ok(found) => substance = Some(found),
+                Err(err) => {
+                    eprintln!("{} : {err}", &args[0]);
+                    return ExitCode::from(1);
+                }
+            }
+        } else {
+            eprintln!("{} : unknown option '{flag}'", &args[0]);
+            return ExitCode::from(1);
+        }
+    }
+
+    // Load any extra unit definitions
+    let mut registry = UnitRegistry::new().with_volume_system(volume_system);
+    if let Some(path) = &units_file {
+        if let Err(err) = registry.load_file(path) {
+            eprintln!("{} : {err}", &args[0]);
+            return ExitCode::from(1);
+        }
+    }
+
+    // List the unit catalog grouped by dimension
+    if args.len() == 2 && args[1] == "units" {
+        print_units(&registry);
+        return ExitCode::SUCCESS;
+    }
+
+    // Evaluate an expression such as "3 gallons + 2 cups in liters"
+    if args.get(1).is_some_and(|command| command == "eval") {
+        if args.len() < 3 {
+            eprintln!("{} : USAGE eval <expression>", &args[0]);
+            return ExitCode::from(1);
+        }
+        return match evaluate_with(&args[2..].join(" "), volume_system) {
+            Ok(result) => {
+                println!("{result}");
+                ExitCode::SUCCESS
+            }
+            Err(ExprError::UnknownUnit(unit)) => {
+                report_unit_error(&args[0], &UnitParseError::UnknownUnit(unit), &registry);
+                ExitCode::from(1)
+            }
+            Err(err) => {
+                eprintln!("{} : {err}", &args[0]);
+                ExitCode::from(1)
+            }
+        };
+    }
+
     if args.len() != 5 {
-        eprintln!("{} : USAGE <input units> <target units> <control> <answer>", &args[0]);
+        eprintln!("{} : USAGE [--explain[=text|markdown|latex]] [--units-file=<path>] [--volume-system=us|imperial] [--substance=<name>] <input units> <target units> <control> <answer>", &args[0]);
+        eprintln!("{} : USAGE eval <expression>", &args[0]);
+        eprintln!("{} : USAGE units", &args[0]);
         return ExitCode::from(1);
     }
 
+    // Units from a definitions file are graded through the registry
+    let defined = |name: &str| Unit::from_str_with(name, volume_system).is_err() && registry.contains(name);
+    if defined(&args[1]) || defined(&args[2]) {
+        if explain_format.is_some() {
+            eprintln!("{} : --explain is not available for units from --units-file", &args[0]);
+            return ExitCode::from(1);
+        }
+        let (Ok(control), Some(answer)) = (args[3].parse::<f64>(), parse_answer(&args[4])) else {
+            println!("Answer: invalid");
+            return ExitCode::from(1);
+        };
+        return match registry.convert(&args[1], &args[2], control) {
+            Ok(expected) => {
+                print_grade(expected, answer);
+                ExitCode::SUCCESS
+            }
+            Err(RegistryError::UnknownUnit(unit)) => {
+                println!("Answer: invalid");
+                report_unit_error(&args[0], &UnitParseError::UnknownUnit(unit), &registry);
+                ExitCode::from(1)
+            }
+            Err(err) => {
+                println!("Answer: invalid");
+                eprintln!("{} : {err}", &args[0]);
+                ExitCode::from(1)
+            }
+        };
+    }
+
+    // Products and quotients of units, such as "gal/min", are graded as
+    // compound and SI-prefixed units
+    let simple = |arg: &str| matches!(PrefixedUnit::from_str_with(arg, volume_system), Ok(unit) if unit.prefix().is_none());
+    if !simple(&args[1]) || !simple(&args[2]) {
+        let compound = |arg: &str| CompoundUnit::from_str_with(arg, volume_system);
+        if let (Ok(from), Ok(to)) = (compound(&args[1]), compound(&args[2])) {
+            if explain_format.is_some() {
+                eprintln!("{} : --explain is not available for units with prefixes or operators", &args[0]);
+                return ExitCode::from(1);
+            }
+            if substance.is_some() {
+                println!("Answer: invalid");
+                eprintln!("{} : --substance is only available for units without prefixes or operators, such as 'cups' and 'grams'", &args[0]);
+                return ExitCode::from(1);
+            }
+            let (Ok(control), Some(answer)) = (args[3].parse::<f64>(), parse_answer(&args[4])) else {
+                println!("Answer: invalid");
+                return ExitCode::from(1);
+            };
+            return match from.convert(&to, control) {
+                Ok(expected) => {
+                    print_grade(expected, answer);
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    println!("Answer: invalid");
+                    eprintln!("{} : {err}", &args[0]);
+                    ExitCode::from(1)
+                }
+            };
+        }
+        for arg in &args[1..3] {
+            let err = match compound(arg) {
+                Err(CompoundUnitError::UnknownUnit(unit)) if unit != *arg => UnitParseError::UnknownUnit(unit),
+                Err(CompoundUnitError::PrefixNotAllowed { prefix, unit }) => UnitParseError::PrefixNotAllowed { prefix, unit },
+                Err(CompoundUnitError::AmbiguousDataSize { text, unit }) => UnitParseError::AmbiguousDataSize { text, unit },
+                Err(err @ (CompoundUnitError::Syntax(_) | CompoundUnitError::InvalidExponent(_))) => {
+                    println!("Answer: invalid");
+                    eprintln!("{} : {err}", &args[0]);
+                    return ExitCode::from(1);
+                }
+                _ => continue,
+            };
+            println!("Answer: invalid");
+            report_unit_error(&args[0], &err, &registry);
+            return ExitCode::from(1);
+        }
+    }
+
     // Parse input and target units using type-safe enums
-    let Ok(uom_in) = Unit::from_str(&args[1]) else {
-        println!("Answer: invalid");
-        return ExitCode::from(1);
+    let uom_in = match Unit::from_str_with(&args[1], volume_system) {
+        Ok(unit) => unit,
+        Err(err) => {
+            println!("Answer: invalid");
+            report_unit_error(&args[0], &err, &registry);
+            return ExitCode::from(1);
+        }
     };
 
-    let Ok(uom_target) = Unit::from_str(&args[2]) else {
-        println!("Answer: invalid");
-        return ExitCode::from(1);
+    let uom_target = match Unit::from_str_with(&args[2], volume_system) {
+        Ok(unit) => unit,
+        Err(err) => {
+            println!("Answer: invalid");
+            report_unit_error(&args[0], &err, &registry);
+            return ExitCode::from(1);
+        }
     };
 
     // Parse control and answer values
@@ -28,22 +198,82 @@ fn main() -> ExitCode {
         return ExitCode::from(1);
     };
 
-    let Ok(answer) = args[4].parse::<f64>() else {
+    let Some(answer) = parse_answer(&args[4]) else {
         println!("Answer: invalid");
         return ExitCode::from(1);
     };
 
-    // Perform type-safe conversion
-    let Some(expected) = convert(uom_in, uom_target, control) else {
+    // Perform type-safe conversion, through the substance's density if given
+    let expected = match substance {
+        Some(substance) => convert_substance(uom_in, uom_target, control, substance),
+        None => convert(uom_in, uom_target, control),
+    };
+    let Some(expected) = expected else {
         println!("Answer: invalid");
         return ExitCode::from(1);
     };
 
-    if approx_eq!(f64, expected, answer, (TOLERANCE_EPSILON, TOLERANCE_ULPS)) {
-        println!("Answer: correct");
-        ExitCode::SUCCESS
+    print_grade(expected, answer);
+
+    // Show the work after grading when requested
+    if let Some(format) = explain_format {
+        let explanation = match substance {
+            Some(substance) => explain_substance(uom_in, uom_target, control, substance),
+            None => explain(uom_in, uom_target, control),
+        };
+        if let Some(explanation) = explanation {
+            println!("{}", explanation.render(format));
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Prints whether the student's answer matches the expected value.
+fn print_grade(expected: f64, answer: Decimal) {
+    println!("Answer: {}", grade(expected, answer));
+}
+
+/// Prints an unknown-unit error and any "did you mean" suggestions, including
+/// units from a definitions file, to stderr.
+fn report_unit_error(program: &str, err: &UnitParseError, registry: &UnitRegistry) {
+    let suggestions = match err {
+        UnitParseError::UnknownUnit(unit) => registry.suggestions(unit),
+        _ => err.suggestions(),
+    };
+    if suggestions.is_empty() {
+        eprintln!("{program} : {err}");
     } else {
-        println!("Answer: incorrect");
-        ExitCode::SUCCESS
+        let quoted: Vec<String> = suggestions.iter().map(|s| format!("'{s}'")).collect();
+        eprintln!("{program} : {err}; did you mean {}?", quoted.join(" or "));
+    }
+}
+
+/// Prints every supported unit with its symbol and accepted aliases, then
+/// any units from a definitions file.
+fn print_units(registry: &UnitRegistry) {
+    for (i, dimension) in Dimension::all().iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let name = dimension.name();
+        println!("{}{}:", name[..1].to_uppercase(), &name[1..]);
+        for unit in dimension.units() {
+            println!("  {:<9} {:<21} {}", unit.symbol(), unit.name(true), unit.aliases().join(", "));
+        }
+    }
+    if registry.definitions().is_empty() && registry.ratios().is_empty() {
+        return;
+    }
+    println!();
+    println!("Defined:");
+    for definition in registry.definitions() {
+        let line = format!("  {:<9} {:<21} {}", definition.name, definition.dimension.name(), definition.aliases.join(", "));
+        println!("{}", line.trim_end());
+    }
+    for ratio in registry.ratios() {
+        let per = format!("{} {}", ratio.amount, ratio.unit.name(ratio.amount != 1.0));
+        let line = format!("  {:<9} {:<21} {}", ratio.name, per, ratio.aliases.join(", "));
+        println!("{}", line.trim_end());
     }
 }
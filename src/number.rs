@@ -0,0 +1,128 @@
+//! Numeric types that conversions can be carried out in.
+//!
+//! [`convert`](crate::convert) and the pairwise helpers such as
+//! [`lit_gal`](crate::lit_gal) are generic over [`Number`], so values can be
+//! converted as `f32` on embedded targets, as `f64`, or as a fixed-point
+//! [`Decimal`] when a result has to match a spreadsheet digit for digit.
+//!
+//! Integers are not numbers in this sense: nearly every conversion factor is
+//! fractional, so an integer would be truncated at every step. Convert
+//! integer readings as `f64` and round the result.
+
+use core::fmt;
+use core::ops::{Add, Div, Mul, Sub};
+
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+
+/// A numeric type that unit conversions can be computed in.
+///
+/// Conversion constants are stored as `f64` and brought into the type with
+/// [`Number::from_f64`]; the arithmetic itself is the type's own, so a
+/// [`Decimal`] conversion by an exact factor such as 231 in³/gal is exact.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{convert, Decimal, Unit};
+///
+/// let single: f32 = convert(Unit::Celsius, Unit::Fahrenheit, 100.0_f32).unwrap();
+/// assert_eq!(single, 212.0);
+///
+/// let exact = convert(Unit::Gallons, Unit::CubicInches, Decimal::new(15, 1)).unwrap();
+/// assert_eq!(exact.to_string(), "346.5");
+/// ```
+pub trait Number:
+    Copy + PartialOrd + fmt::Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    /// Converts an `f64`, such as a conversion constant, into this type.
+    ///
+    /// # Panics
+    ///
+    /// Panics for [`Decimal`] if `value` is NaN or infinite.
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts this value to the nearest `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Converts this value to the shortest decimal that round-trips to it,
+    /// or `None` if it is NaN or infinite.
+    fn to_decimal(self) -> Option<Decimal>;
+}
+
+impl Number for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn to_decimal(self) -> Option<Decimal> {
+        <Decimal as FromPrimitive>::from_f64(self)
+    }
+}
+
+impl Number for f32 {
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    /// Uses the shortest digits of the `f32` itself, so `0.35_f32` is 0.35
+    /// rather than 0.3499999940395355.
+    fn to_decimal(self) -> Option<Decimal> {
+        Decimal::from_f32(self)
+    }
+}
+
+impl Number for Decimal {
+    /// Keeps only the digits the `f64` actually carries, so `0.2641`
+    /// becomes exactly 0.2641 rather than its binary approximation.
+    fn from_f64(value: f64) -> Self {
+        <Decimal as FromPrimitive>::from_f64(value).expect("decimal conversions need finite values")
+    }
+
+    fn to_f64(self) -> f64 {
+        <Decimal as ToPrimitive>::to_f64(&self).unwrap_or(f64::NAN)
+    }
+
+    fn to_decimal(self) -> Option<Decimal> {
+        Some(self)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{convert, lit_gal, Unit};
+    use float_cmp::approx_eq;
+
+    #[test]
+    // test conversions in f32 track the f64 results
+    fn test_convert_f32() {
+        for &from in Unit::all() {
+            for &to in Unit::all() {
+                let Some(expected) = convert(from, to, 12.5_f64) else { continue };
+                let result = convert(from, to, 12.5_f32).unwrap();
+                assert!(approx_eq!(f32, result, expected as f32, epsilon = 1e-4 * expected.abs().max(1.0) as f32), "{from} -> {to}");
+            }
+        }
+    }
+
+    #[test]
+    // test decimal conversions use the published factors exactly
+    fn test_convert_decimal() {
+        assert_eq!(lit_gal(Decimal::new(3, 0)), Decimal::new(7923, 4));
+        assert_eq!(convert(Unit::Celsius, Unit::Kelvin, Decimal::new(-4015, 2)), Some(Decimal::new(23300, 2)));
+        assert_eq!(convert(Unit::Feet, Unit::Inches, Decimal::new(25, 1)), Some(Decimal::new(30, 0)));
+        let fahrenheit = convert(Unit::Celsius, Unit::Fahrenheit, Decimal::new(37, 0)).unwrap();
+        assert_eq!(fahrenheit.to_f64(), 98.6);
+        assert_eq!(convert(Unit::Liters, Unit::Kelvin, Decimal::ONE), None);
+    }
+}
@@ -0,0 +1,659 @@
+//! Unit-name parsing with aliases, symbols, and spelling variants.
+//!
+//! Input is normalized before lookup so that case, separators, degree signs,
+//! superscripts, and trailing periods do not matter:
+//!
+//! - `"°F"`, `"deg F"`, `"degrees Fahrenheit"` → `"f"` / `"fahrenheit"`
+//! - `"Cubic_Inches"`, `"cubic-inches"` → `"cubic inches"`
+//! - `"in³"` → `"in^3"`, `"tbsp."` → `"tbsp"`
+//!
+//! The normalized text is then matched against each unit's alias table,
+//! first as written and then with a plural `-s` / `-es` ending removed.
+//!
+//! Bare names shared by the US customary and Imperial systems ("gallon",
+//! "pt", "fl oz", ...) resolve according to a [`VolumeSystem`]; names
+//! qualified with "US", "imperial", or "UK" are never ambiguous.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Dimension, Unit, UnitParseError};
+
+/// The system used to resolve volume names shared by US customary and
+/// Imperial (UK) units, such as "gallon", "quart", "pint", "cup",
+/// "fluid ounce", and "tablespoon".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum VolumeSystem {
+    /// US customary liquid measures
+    #[default]
+    UsCustomary,
+    /// Imperial (UK) measures
+    Imperial,
+}
+
+/// Error type for parsing a [`VolumeSystem`] from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VolumeSystemParseError(pub String);
+
+impl fmt::Display for VolumeSystemParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown volume system: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for VolumeSystemParseError {}
+
+impl FromStr for VolumeSystem {
+    type Err = VolumeSystemParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "US" | "USA" | "CUSTOMARY" | "US CUSTOMARY" => Ok(Self::UsCustomary),
+            "IMPERIAL" | "UK" | "IMP" => Ok(Self::Imperial),
+            _ => Err(VolumeSystemParseError(s.to_string())),
+        }
+    }
+}
+
+impl Unit {
+    /// Returns the names, abbreviations, and symbols accepted for this unit.
+    ///
+    /// Aliases are listed in normalized form: lowercase, words separated by a
+    /// single space, and superscripts written as `^2` / `^3`. Regular plurals
+    /// (`-s`, `-es`) are accepted without being listed. No alias belongs to
+    /// more than one unit, although the bare aliases of US liquid measures
+    /// resolve to the Imperial unit when parsing in [`VolumeSystem::Imperial`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::Unit;
+    ///
+    /// assert!(Unit::Liters.aliases().contains(&"litre"));
+    /// ```
+    #[must_use]
+    pub fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Self::Kelvin => &["kelvin", "k"],
+            Self::Celsius => &["celsius", "c", "centigrade", "degc"],
+            Self::Fahrenheit => &["fahrenheit", "f", "degf"],
+            Self::Rankine => &["rankine", "r", "ra", "degr"],
+            Self::Reaumur => &["réaumur", "reaumur", "ré", "degré"],
+            Self::Romer => &["rømer", "romer", "roemer", "rø", "degrø", "degro"],
+            Self::Delisle => &["delisle", "degde"],
+            Self::Newton => &["newton", "degn"],
+            Self::Liters => &["liter", "litre", "l", "ltr", "lt"],
+            Self::Tablespoons => &[
+                "tablespoon",
+                "tbsp",
+                "tbs",
+                "tbl",
+                "tblsp",
+                "us tablespoon",
+                "us tbsp",
+            ],
+            Self::CubicInches => &[
+                "cubic inch",
+                "cubicinch",
+                "cubic in",
+                "cu in",
+                "in^3",
+                "in3",
+            ],
+            Self::Cups => &["cup", "us cup"],
+            Self::CubicFeet => &[
+                "cubic foot",
+                "cubic feet",
+                "cubicfoot",
+                "cubicfeet",
+                "cubic ft",
+                "cu ft",
+                "ft^3",
+                "ft3",
+            ],
+            Self::Gallons => &["gallon", "gal", "us gallon", "us liquid gallon", "us gal"],
+            Self::Milliliters => &["milliliter", "millilitre", "ml", "cc", "cm^3", "cm3"],
+            Self::Teaspoons => &["teaspoon", "tsp", "tspn"],
+            Self::FluidOunces => &[
+                "fluid ounce",
+                "fl oz",
+                "floz",
+                "fl ounce",
+                "oz fl",
+                "us fluid ounce",
+                "us fl oz",
+            ],
+            Self::Pints => &["pint", "pt", "us pint", "us liquid pint", "us pt"],
+            Self::Quarts => &["quart", "qt", "us quart", "us liquid quart", "us qt"],
+            Self::CubicMeters => &[
+                "cubic meter",
+                "cubic metre",
+                "cubicmeter",
+                "cubicmetre",
+                "cubic m",
+                "cu m",
+                "m^3",
+                "m3",
+            ],
+            Self::Barrels => &["barrel", "bbl"],
+            Self::ImperialGallons => &[
+                "imperial gallon",
+                "imp gallon",
+                "uk gallon",
+                "imperial gal",
+                "imp gal",
+                "uk gal",
+            ],
+            Self::ImperialQuarts => &[
+                "imperial quart",
+                "imp quart",
+                "uk quart",
+                "imperial qt",
+                "imp qt",
+                "uk qt",
+            ],
+            Self::ImperialPints => &[
+                "imperial pint",
+                "imp pint",
+                "uk pint",
+                "imperial pt",
+                "imp pt",
+                "uk pt",
+            ],
+            Self::ImperialCups => &["imperial cup", "imp cup", "uk cup"],
+            Self::ImperialFluidOunces => &[
+                "imperial fluid ounce",
+                "imp fluid ounce",
+                "uk fluid ounce",
+                "imperial fl oz",
+                "imp fl oz",
+                "uk fl oz",
+            ],
+            Self::ImperialTablespoons => &[
+                "imperial tablespoon",
+                "imp tablespoon",
+                "uk tablespoon",
+                "imperial tbsp",
+                "imp tbsp",
+                "uk tbsp",
+            ],
+            Self::DryPints => &["dry pint", "us dry pint", "dry pt"],
+            Self::DryQuarts => &["dry quart", "us dry quart", "dry qt"],
+            Self::DryGallons => &["dry gallon", "us dry gallon", "dry gal"],
+            Self::Pecks => &["peck", "pk"],
+            Self::Bushels => &["bushel", "bu"],
+            Self::SquareMeters => &[
+                "square meter",
+                "square metre",
+                "sq meter",
+                "sq metre",
+                "sq m",
+                "sqm",
+                "m^2",
+                "m2",
+            ],
+            Self::SquareFeet => &[
+                "square foot",
+                "square feet",
+                "sq foot",
+                "sq feet",
+                "sq ft",
+                "sqft",
+                "ft^2",
+                "ft2",
+            ],
+            Self::Acres => &["acre", "ac"],
+            Self::Hectares => &["hectare", "ha"],
+            Self::SquareMiles => &["square mile", "sq mile", "sq mi", "mi^2", "mi2"],
+            Self::Meters => &["meter", "metre", "m"],
+            Self::Centimeters => &["centimeter", "centimetre", "cm"],
+            Self::Millimeters => &["millimeter", "millimetre", "mm"],
+            Self::Kilometers => &["kilometer", "kilometre", "km"],
+            Self::Inches => &["inch", "in"],
+            Self::Feet => &["foot", "feet", "ft"],
+            Self::Yards => &["yard", "yd", "yds"],
+            Self::Miles => &["mile", "mi"],
+            Self::Kilograms => &["kilogram", "kilo", "kg", "kgs"],
+            Self::Grams => &["gram", "gramme", "g", "gm"],
+            Self::Milligrams => &["milligram", "milligramme", "mg"],
+            Self::Pounds => &["pound", "lb", "lbs", "lbm"],
+            Self::Ounces => &["ounce", "oz", "ozs"],
+            Self::Seconds => &["second", "sec", "s"],
+            Self::Minutes => &["minute", "min"],
+            Self::Hours => &["hour", "hr", "hrs", "h"],
+            Self::Days => &["day", "d"],
+            Self::Weeks => &["week", "wk", "wks"],
+            Self::Joules => &["joule", "j"],
+            Self::Kilojoules => &["kilojoule", "kj"],
+            Self::Calories => &["calorie", "cal", "small calorie", "gram calorie"],
+            Self::Kilocalories => &["kilocalorie", "kcal", "food calorie", "large calorie"],
+            Self::Btu => &["british thermal unit", "btu"],
+            Self::KilowattHours => &["kilowatt hour", "kilowatthour", "kwh", "kw h"],
+            Self::Watts => &["watt", "w"],
+            Self::Kilowatts => &["kilowatt", "kw"],
+            Self::Horsepower => &["horsepower", "hp"],
+            Self::Pascals => &["pascal", "pa"],
+            Self::Kilopascals => &["kilopascal", "kpa"],
+            Self::Atmospheres => &["atmosphere", "atm"],
+            Self::Psi => &[
+                "pound per square inch",
+                "pounds per square inch",
+                "psi",
+                "lbf/in^2",
+                "lbf/in2",
+            ],
+            Self::MillimetersOfMercury => &[
+                "millimeter of mercury",
+                "millimeters of mercury",
+                "millimetre of mercury",
+                "millimetres of mercury",
+                "mmhg",
+                "mm hg",
+            ],
+            Self::Bars => &["bar"],
+            Self::Bits => &["bit"],
+            Self::Bytes => &["byte", "b"],
+            Self::Kilobytes => &["kilobyte", "kb", "kbyte"],
+            Self::Megabytes => &["megabyte", "mb", "mbyte"],
+            Self::Gigabytes => &["gigabyte", "gb", "gbyte"],
+            Self::Terabytes => &["terabyte", "tb", "tbyte"],
+            Self::Kibibytes => &["kibibyte", "kib"],
+            Self::Mebibytes => &["mebibyte", "mib"],
+            Self::Gibibytes => &["gibibyte", "gib"],
+            Self::Tebibytes => &["tebibyte", "tib"],
+        }
+    }
+}
+
+/// Parses a unit name, abbreviation, or symbol, resolving bare US/Imperial
+/// names in the given volume system.
+pub(crate) fn parse_unit(s: &str, system: VolumeSystem) -> Option<Unit> {
+    let normalized = normalize(s);
+    if normalized.is_empty() {
+        return None;
+    }
+    let unit = lookup(&normalized)
+        .or_else(|| singular(&normalized, "es").and_then(lookup))
+        .or_else(|| singular(&normalized, "s").and_then(lookup))
+        .or_else(|| degree_marked(s).then(|| lookup(&format!("deg{normalized}"))).flatten())?;
+    match system {
+        VolumeSystem::Imperial if !normalized.starts_with("us ") => Some(imperial(unit).unwrap_or(unit)),
+        _ => Some(unit),
+    }
+}
+
+/// Parses the name, alias, or symbol of a built-in unit, without splitting
+/// off SI prefixes.
+pub(crate) fn parse_builtin(s: &str, system: VolumeSystem) -> Result<Unit, UnitParseError> {
+    match parse_unit(s, system) {
+        Some(unit) if is_ambiguous_data_size(s, unit) => {
+            Err(UnitParseError::AmbiguousDataSize { text: s.to_string(), unit })
+        }
+        Some(unit) => Ok(unit),
+        None => Err(UnitParseError::UnknownUnit(s.to_string())),
+    }
+}
+
+/// Returns true if `s` writes the symbol of a byte unit with a lower-case
+/// "b", as in "Mb", "kib", or "Mbs", which conventionally means bits.
+pub(crate) fn is_ambiguous_data_size(s: &str, unit: Unit) -> bool {
+    let text = s.trim().trim_end_matches('.');
+    let text = text.strip_suffix('s').filter(|stem| stem.chars().count() > 1).unwrap_or(text);
+    unit.dimension() == Dimension::DataSize
+        && unit != Unit::Bits
+        && normalize(text) == unit.symbol().to_lowercase()
+        && text.ends_with('b')
+}
+
+/// Returns the unit a bare US liquid measure stands for in `system`.
+pub(crate) fn in_system(unit: Unit, system: VolumeSystem) -> Unit {
+    match system {
+        VolumeSystem::UsCustomary => unit,
+        VolumeSystem::Imperial => imperial(unit).unwrap_or(unit),
+    }
+}
+
+/// Returns the Imperial unit sharing a bare name with a US liquid measure.
+fn imperial(unit: Unit) -> Option<Unit> {
+    match unit {
+        Unit::Gallons => Some(Unit::ImperialGallons),
+        Unit::Quarts => Some(Unit::ImperialQuarts),
+        Unit::Pints => Some(Unit::ImperialPints),
+        Unit::Cups => Some(Unit::ImperialCups),
+        Unit::FluidOunces => Some(Unit::ImperialFluidOunces),
+        Unit::Tablespoons => Some(Unit::ImperialTablespoons),
+        _ => None,
+    }
+}
+
+/// Returns known spellings close to an unrecognized unit name, best first.
+///
+/// Each unit is suggested at most once, using whichever of its aliases (or
+/// their plurals) is closest to the input. Inputs shorter than three
+/// characters get no suggestions, since almost every short symbol is within
+/// one edit of them.
+pub(crate) fn suggestions(s: &str) -> Vec<String> {
+    closest(s, Unit::all().iter().map(|unit| unit.aliases().iter().copied()))
+}
+
+/// Ranks the names of each unit in `units` by their distance from `s`, as
+/// described for [`suggestions`].
+pub(crate) fn closest<'a, U, N>(s: &str, units: U) -> Vec<String>
+where
+    U: IntoIterator<Item = N>,
+    N: IntoIterator<Item = &'a str>,
+{
+    let normalized = normalize(s);
+    let length = normalized.chars().count();
+    if length < 3 {
+        return Vec::new();
+    }
+    let max_distance = (length / 3).max(1);
+
+    let mut ranked: Vec<(usize, usize, String)> = units
+        .into_iter()
+        .enumerate()
+        .filter_map(|(order, names)| {
+            names
+                .into_iter()
+                .flat_map(|alias| {
+                    // Only offer a plural that the parser would read back
+                    let plural = format!("{alias}s");
+                    let plural = singular(&normalize(&plural), "s").is_some().then_some(plural);
+                    std::iter::once(alias.to_string()).chain(plural)
+                })
+                .map(|candidate| (edit_distance(&normalized, &normalize(&candidate)), candidate))
+                .min_by_key(|(distance, _)| *distance)
+                .filter(|(distance, _)| *distance <= max_distance)
+                .map(|(distance, candidate)| (distance, order, candidate))
+        })
+        .collect();
+    ranked.sort();
+    ranked.into_iter().take(MAX_SUGGESTIONS).map(|(_, _, candidate)| candidate).collect()
+}
+
+/// Maximum number of suggestions returned for an unknown unit.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Returns the optimal string alignment distance between two strings: the
+/// number of insertions, deletions, substitutions, and adjacent
+/// transpositions needed to turn one into the other.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    rows[0] = (0..=b.len()).collect();
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Finds the unit with an exactly matching alias.
+fn lookup(name: &str) -> Option<Unit> {
+    Unit::all().iter().copied().find(|unit| unit.aliases().contains(&name))
+}
+
+/// Returns true if `s` is marked as a temperature with a degree sign or a
+/// leading "deg" / "degree(s)" word, which normalization drops. Symbols such
+/// as "N" and "De" only name temperature scales when marked this way.
+fn degree_marked(s: &str) -> bool {
+    let s = s.trim();
+    let mut words = s.split(|c: char| c.is_whitespace() || c == '-' || c == '_').filter(|word| !word.is_empty());
+    s.starts_with(['°', 'º'])
+        || matches!(words.next().map(str::to_lowercase).as_deref(), Some("deg" | "degree" | "degrees"))
+            && words.next().is_some()
+}
+
+/// Strips a plural ending, keeping at least two characters of the stem so
+/// that one-letter symbols are never produced by accident: "pts" and "mls"
+/// are plurals, but "ks" is not.
+fn singular<'a>(name: &'a str, ending: &str) -> Option<&'a str> {
+    name.strip_suffix(ending).filter(|stem| stem.chars().count() > 1)
+}
+
+/// Normalizes a unit name for alias lookup.
+pub(crate) fn normalize(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.trim().chars() {
+        match c {
+            // Degree signs (including the masculine ordinal often typed
+            // instead) and periods carry no meaning for lookup.
+            '°' | 'º' | '.' => {}
+            '²' => out.push_str("^2"),
+            '³' => out.push_str("^3"),
+            '-' | '_' => out.push(' '),
+            c if c.is_whitespace() => out.push(' '),
+            c => out.extend(c.to_lowercase()),
+        }
+    }
+
+    let words: Vec<&str> = out.split_whitespace().collect();
+    let words = match words.as_slice() {
+        ["degrees" | "degree" | "deg", rest @ ..] if !rest.is_empty() => rest,
+        words => words,
+    };
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    #[test]
+    // test no alias (or alias with a plural ending) is claimed by two units;
+    // the only exception is "tbs", which is tablespoons rather than terabytes
+    fn test_aliases_unambiguous() {
+        let mut seen = HashMap::new();
+        for &unit in Unit::all() {
+            for alias in unit.aliases() {
+                assert_eq!(normalize(alias), *alias, "alias not normalized: {alias}");
+                if let Some(other) = seen.insert(*alias, unit) {
+                    panic!("alias {alias} used by {other:?} and {unit:?}");
+                }
+            }
+        }
+        for &unit in Unit::all() {
+            for alias in unit.aliases() {
+                for plural in [format!("{alias}s"), format!("{alias}es")] {
+                    if plural == "tbs" {
+                        continue;
+                    }
+                    assert!(
+                        parse_unit(&plural, VolumeSystem::UsCustomary).is_none_or(|found| found == unit),
+                        "{plural} parses as another unit"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    // test symbols, degree signs, and Unicode superscripts
+    fn test_parse_symbols() {
+        assert_eq!(Unit::from_str("°F"), Ok(Unit::Fahrenheit));
+        assert_eq!(Unit::from_str("F"), Ok(Unit::Fahrenheit));
+        assert_eq!(Unit::from_str("ºC"), Ok(Unit::Celsius));
+        assert_eq!(Unit::from_str("K"), Ok(Unit::Kelvin));
+        assert_eq!(Unit::from_str("in³"), Ok(Unit::CubicInches));
+        assert_eq!(Unit::from_str("in^3"), Ok(Unit::CubicInches));
+        assert_eq!(Unit::from_str("ft³"), Ok(Unit::CubicFeet));
+        assert_eq!(Unit::from_str("L"), Ok(Unit::Liters));
+        assert_eq!(Unit::from_str("°Ré"), Ok(Unit::Reaumur));
+        assert_eq!(Unit::from_str("°Rø"), Ok(Unit::Romer));
+        assert_eq!(Unit::from_str("°De"), Ok(Unit::Delisle));
+        assert_eq!(Unit::from_str("°N"), Ok(Unit::Newton));
+    }
+
+    #[test]
+    // test bare "N", "De", "Re", and "Ro" only name temperature scales when
+    // marked with a degree sign or word
+    fn test_parse_marked_temperatures() {
+        for bare in ["N", "n", "De", "Re", "Ro"] {
+            assert_eq!(Unit::from_str(bare), Err(UnitParseError::UnknownUnit(bare.to_string())), "{bare}");
+        }
+        assert_eq!(Unit::from_str("degN"), Ok(Unit::Newton));
+        assert_eq!(Unit::from_str("deg N"), Ok(Unit::Newton));
+        assert_eq!(Unit::from_str("degrees-N"), Ok(Unit::Newton));
+        assert_eq!(Unit::from_str("°Ro"), Ok(Unit::Romer));
+        assert_eq!(Unit::from_str("deg De"), Ok(Unit::Delisle));
+        for &unit in Unit::all() {
+            assert_eq!(Unit::from_str(unit.symbol()), Ok(unit), "{unit:?}");
+        }
+    }
+
+    #[test]
+    // test abbreviations, singular forms, and British spellings
+    fn test_parse_words() {
+        assert_eq!(Unit::from_str("liter"), Ok(Unit::Liters));
+        assert_eq!(Unit::from_str("litre"), Ok(Unit::Liters));
+        assert_eq!(Unit::from_str("Litres"), Ok(Unit::Liters));
+        assert_eq!(Unit::from_str("tbsp"), Ok(Unit::Tablespoons));
+        assert_eq!(Unit::from_str("Tbsp."), Ok(Unit::Tablespoons));
+        assert_eq!(Unit::from_str("cup"), Ok(Unit::Cups));
+        assert_eq!(Unit::from_str("gal"), Ok(Unit::Gallons));
+        assert_eq!(Unit::from_str("gals"), Ok(Unit::Gallons));
+        assert_eq!(Unit::from_str("cubic inch"), Ok(Unit::CubicInches));
+        assert_eq!(Unit::from_str("cubic foot"), Ok(Unit::CubicFeet));
+        assert_eq!(Unit::from_str("kelvins"), Ok(Unit::Kelvin));
+        assert_eq!(Unit::from_str("mL"), Ok(Unit::Milliliters));
+        assert_eq!(Unit::from_str("tsp"), Ok(Unit::Teaspoons));
+        assert_eq!(Unit::from_str("fl. oz."), Ok(Unit::FluidOunces));
+        assert_eq!(Unit::from_str("fluid ounces"), Ok(Unit::FluidOunces));
+        assert_eq!(Unit::from_str("pints"), Ok(Unit::Pints));
+        assert_eq!(Unit::from_str("qt"), Ok(Unit::Quarts));
+        assert_eq!(Unit::from_str("m³"), Ok(Unit::CubicMeters));
+        assert_eq!(Unit::from_str("cubic metres"), Ok(Unit::CubicMeters));
+        assert_eq!(Unit::from_str("bbl"), Ok(Unit::Barrels));
+        assert_eq!(Unit::from_str("m²"), Ok(Unit::SquareMeters));
+        assert_eq!(Unit::from_str("sq. ft."), Ok(Unit::SquareFeet));
+        assert_eq!(Unit::from_str("acres"), Ok(Unit::Acres));
+        assert_eq!(Unit::from_str("ha"), Ok(Unit::Hectares));
+        assert_eq!(Unit::from_str("square miles"), Ok(Unit::SquareMiles));
+        assert_eq!(Unit::from_str("feet"), Ok(Unit::Feet));
+        assert_eq!(Unit::from_str("km"), Ok(Unit::Kilometers));
+        assert_eq!(Unit::from_str("lbs"), Ok(Unit::Pounds));
+        assert_eq!(Unit::from_str("oz"), Ok(Unit::Ounces));
+        assert_eq!(Unit::from_str("fl oz"), Ok(Unit::FluidOunces));
+        assert_eq!(Unit::from_str("mins"), Ok(Unit::Minutes));
+        assert_eq!(Unit::from_str("hrs"), Ok(Unit::Hours));
+        assert_eq!(Unit::from_str("kWh"), Ok(Unit::KilowattHours));
+        assert_eq!(Unit::from_str("BTUs"), Ok(Unit::Btu));
+        assert_eq!(Unit::from_str("kcal"), Ok(Unit::Kilocalories));
+        assert_eq!(Unit::from_str("hp"), Ok(Unit::Horsepower));
+        assert_eq!(Unit::from_str("psi"), Ok(Unit::Psi));
+        assert_eq!(Unit::from_str("mmHg"), Ok(Unit::MillimetersOfMercury));
+        assert_eq!(Unit::from_str("bars"), Ok(Unit::Bars));
+        assert_eq!(Unit::from_str("atm"), Ok(Unit::Atmospheres));
+        assert_eq!(Unit::from_str("days"), Ok(Unit::Days));
+        assert_eq!(Unit::from_str("wks"), Ok(Unit::Weeks));
+        assert_eq!(Unit::from_str("KB"), Ok(Unit::Kilobytes));
+        assert_eq!(Unit::from_str("KiB"), Ok(Unit::Kibibytes));
+        assert_eq!(Unit::from_str("GiB"), Ok(Unit::Gibibytes));
+        assert_eq!(Unit::from_str("megabytes"), Ok(Unit::Megabytes));
+        assert_eq!(Unit::from_str("bits"), Ok(Unit::Bits));
+    }
+
+    #[test]
+    // test bare US/Imperial names follow the volume system, qualified names do not
+    fn test_parse_volume_systems() {
+        let imperial = VolumeSystem::Imperial;
+        let us = VolumeSystem::UsCustomary;
+        assert_eq!(Unit::from_str_with("gallons", us), Ok(Unit::Gallons));
+        assert_eq!(Unit::from_str_with("gallons", imperial), Ok(Unit::ImperialGallons));
+        assert_eq!(Unit::from_str_with("fl oz", imperial), Ok(Unit::ImperialFluidOunces));
+        assert_eq!(Unit::from_str_with("tbsp", imperial), Ok(Unit::ImperialTablespoons));
+        assert_eq!(Unit::from_str_with("U.S. pints", imperial), Ok(Unit::Pints));
+        assert_eq!(Unit::from_str_with("US cup", imperial), Ok(Unit::Cups));
+        assert_eq!(Unit::from_str_with("imperial quarts", us), Ok(Unit::ImperialQuarts));
+        assert_eq!(Unit::from_str_with("UK pint", us), Ok(Unit::ImperialPints));
+        assert_eq!(Unit::from_str_with("imp gal", us), Ok(Unit::ImperialGallons));
+        assert_eq!(Unit::from_str_with("dry pints", imperial), Ok(Unit::DryPints));
+        assert_eq!(Unit::from_str_with("bushels", imperial), Ok(Unit::Bushels));
+        assert_eq!(Unit::from_str_with("liters", imperial), Ok(Unit::Liters));
+        assert_eq!(VolumeSystem::from_str("UK"), Ok(imperial));
+        assert_eq!(VolumeSystem::from_str("us"), Ok(us));
+        assert!(VolumeSystem::from_str("metric").is_err());
+    }
+
+    #[test]
+    // test whitespace, hyphen, and underscore normalization
+    fn test_parse_separators() {
+        assert_eq!(Unit::from_str("cu ft"), Ok(Unit::CubicFeet));
+        assert_eq!(Unit::from_str("  cu   ft "), Ok(Unit::CubicFeet));
+        assert_eq!(Unit::from_str("cubic_feet"), Ok(Unit::CubicFeet));
+        assert_eq!(Unit::from_str("CUBIC-INCHES"), Ok(Unit::CubicInches));
+        assert_eq!(Unit::from_str("cubicinches"), Ok(Unit::CubicInches));
+        assert_eq!(Unit::from_str("degrees Fahrenheit"), Ok(Unit::Fahrenheit));
+        assert_eq!(Unit::from_str("deg C"), Ok(Unit::Celsius));
+    }
+
+    #[test]
+    // test edit distance counts transpositions as one edit
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("farenheit", "fahrenheit"), 1);
+        assert_eq!(edit_distance("ltiers", "liters"), 1);
+        assert_eq!(edit_distance("cup", "cup"), 0);
+        assert_eq!(edit_distance("", "gal"), 3);
+    }
+
+    #[test]
+    // test plurals of two-letter symbols, but not of one-letter ones
+    fn test_parse_symbol_plurals() {
+        assert_eq!(Unit::from_str("pts"), Ok(Unit::Pints));
+        assert_eq!(Unit::from_str("qts"), Ok(Unit::Quarts));
+        assert_eq!(Unit::from_str("mls"), Ok(Unit::Milliliters));
+        assert_eq!(Unit::from_str("cms"), Ok(Unit::Centimeters));
+        assert_eq!(
+            Unit::from_str("Mbs"),
+            Err(UnitParseError::AmbiguousDataSize { text: "Mbs".to_string(), unit: Unit::Megabytes })
+        );
+        assert_eq!(parse_unit("ks", VolumeSystem::UsCustomary), None);
+    }
+
+    #[test]
+    // test suggestions for misspelled units
+    fn test_suggestions() {
+        assert_eq!(suggestions("farenheit"), vec!["fahrenheit"]);
+        assert_eq!(suggestions("Celcius"), vec!["celsius"]);
+        assert_eq!(suggestions("galons"), vec!["gallons"]);
+        assert_eq!(suggestions("tablspoons"), vec!["tablespoons", "teaspoons"]);
+        assert!(suggestions("kg").is_empty());
+        assert!(suggestions("dog").is_empty());
+    }
+
+    #[test]
+    // test every suggestion parses, so plurals of one-letter symbols such as
+    // "ks" are never offered
+    fn test_suggestions_parse() {
+        assert!(!suggestions("kss").contains(&"ks".to_string()));
+        for input in ["kss", "pyts", "qtz", "mlss", "cmz", "galz", "ounse", "kelvn"] {
+            for suggestion in suggestions(input) {
+                assert!(Unit::from_str(&suggestion).is_ok(), "{input} suggests {suggestion}");
+            }
+        }
+    }
+
+    #[test]
+    // test inputs that must not parse
+    fn test_parse_rejects() {
+        assert!(Unit::from_str("").is_err());
+        assert!(Unit::from_str("degrees").is_err());
+        assert!(Unit::from_str("ks").is_err());
+        assert!(Unit::from_str("cubic").is_err());
+    }
+}
@@ -0,0 +1,598 @@
+//! SI prefixes on metric units.
+//!
+//! Any metric unit (liters, meters, grams, seconds, ...) accepts an SI prefix
+//! from yocto (10⁻²⁴) to yotta (10²⁴), written either as a symbol (`mL`,
+//! `µs`, `GW`) or as a word (`kilolitre`, `microseconds`). A [`PrefixedUnit`]
+//! pairs the prefix with the unit; units that already have their own variant,
+//! such as [`Unit::Milliliters`] or [`Unit::Kilograms`], are parsed as that
+//! variant.
+//!
+//! Prefix symbols are case-sensitive, as in SI: `ML` is megaliters and `mL`
+//! milliliters, `Mg` is megagrams and `mg` milligrams. Everything after the
+//! prefix follows the usual case-insensitive unit parsing. Prefixes on
+//! non-metric units, such as `kgal`, are rejected.
+//!
+//! [`Unit::from_str`](std::str::FromStr::from_str), the expression
+//! evaluator, and the unit registry all read text through this parser, so
+//! they agree on what a symbol means.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::parse::{in_system, parse_builtin};
+use crate::{convert, Dimension, Unit, UnitParseError, VolumeSystem};
+
+/// An SI prefix, scaling a metric unit by a power of ten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Prefix {
+    /// 10⁻²⁴
+    Yocto,
+    /// 10⁻²¹
+    Zepto,
+    /// 10⁻¹⁸
+    Atto,
+    /// 10⁻¹⁵
+    Femto,
+    /// 10⁻¹²
+    Pico,
+    /// 10⁻⁹
+    Nano,
+    /// 10⁻⁶
+    Micro,
+    /// 10⁻³
+    Milli,
+    /// 10⁻²
+    Centi,
+    /// 10⁻¹
+    Deci,
+    /// 10¹
+    Deca,
+    /// 10²
+    Hecto,
+    /// 10³
+    Kilo,
+    /// 10⁶
+    Mega,
+    /// 10⁹
+    Giga,
+    /// 10¹²
+    Tera,
+    /// 10¹⁵
+    Peta,
+    /// 10¹⁸
+    Exa,
+    /// 10²¹
+    Zetta,
+    /// 10²⁴
+    Yotta,
+}
+
+/// Every prefix, from smallest to largest.
+const ALL_PREFIXES: [Prefix; 20] = [
+    Prefix::Yocto,
+    Prefix::Zepto,
+    Prefix::Atto,
+    Prefix::Femto,
+    Prefix::Pico,
+    Prefix::Nano,
+    Prefix::Micro,
+    Prefix::Milli,
+    Prefix::Centi,
+    Prefix::Deci,
+    Prefix::Deca,
+    Prefix::Hecto,
+    Prefix::Kilo,
+    Prefix::Mega,
+    Prefix::Giga,
+    Prefix::Tera,
+    Prefix::Peta,
+    Prefix::Exa,
+    Prefix::Zetta,
+    Prefix::Yotta,
+];
+
+impl Prefix {
+    /// Returns every prefix, from yocto to yotta.
+    #[must_use]
+    pub const fn all() -> &'static [Prefix] {
+        &ALL_PREFIXES
+    }
+
+    /// Returns the power of ten this prefix stands for.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::Prefix;
+    ///
+    /// assert_eq!(Prefix::Micro.exponent(), -6);
+    /// ```
+    #[must_use]
+    pub const fn exponent(self) -> i32 {
+        match self {
+            Self::Yocto => -24,
+            Self::Zepto => -21,
+            Self::Atto => -18,
+            Self::Femto => -15,
+            Self::Pico => -12,
+            Self::Nano => -9,
+            Self::Micro => -6,
+            Self::Milli => -3,
+            Self::Centi => -2,
+            Self::Deci => -1,
+            Self::Deca => 1,
+            Self::Hecto => 2,
+            Self::Kilo => 3,
+            Self::Mega => 6,
+            Self::Giga => 9,
+            Self::Tera => 12,
+            Self::Peta => 15,
+            Self::Exa => 18,
+            Self::Zetta => 21,
+            Self::Yotta => 24,
+        }
+    }
+
+    /// Returns the factor this prefix multiplies a unit by, e.g. 0.001 for milli.
+    #[must_use]
+    pub fn factor(self) -> f64 {
+        // Negative powers are computed by division so that 10⁻³ is the
+        // closest f64 to 0.001 rather than an approximation of its inverse.
+        let exponent = self.exponent();
+        if exponent < 0 {
+            1.0 / 10f64.powi(-exponent)
+        } else {
+            10f64.powi(exponent)
+        }
+    }
+
+    /// Returns the SI symbol for this prefix, e.g. "µ" or "k".
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Yocto => "y",
+            Self::Zepto => "z",
+            Self::Atto => "a",
+            Self::Femto => "f",
+            Self::Pico => "p",
+            Self::Nano => "n",
+            Self::Micro => "µ",
+            Self::Milli => "m",
+            Self::Centi => "c",
+            Self::Deci => "d",
+            Self::Deca => "da",
+            Self::Hecto => "h",
+            Self::Kilo => "k",
+            Self::Mega => "M",
+            Self::Giga => "G",
+            Self::Tera => "T",
+            Self::Peta => "P",
+            Self::Exa => "E",
+            Self::Zetta => "Z",
+            Self::Yotta => "Y",
+        }
+    }
+
+    /// Returns the prefix as written before a unit name, e.g. "micro".
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Yocto => "yocto",
+            Self::Zepto => "zepto",
+            Self::Atto => "atto",
+            Self::Femto => "femto",
+            Self::Pico => "pico",
+            Self::Nano => "nano",
+            Self::Micro => "micro",
+            Self::Milli => "milli",
+            Self::Centi => "centi",
+            Self::Deci => "deci",
+            Self::Deca => "deca",
+            Self::Hecto => "hecto",
+            Self::Kilo => "kilo",
+            Self::Mega => "mega",
+            Self::Giga => "giga",
+            Self::Tera => "tera",
+            Self::Peta => "peta",
+            Self::Exa => "exa",
+            Self::Zetta => "zetta",
+            Self::Yotta => "yotta",
+        }
+    }
+
+    /// Returns the symbols accepted for this prefix: the SI symbol, plus
+    /// `u` and the Greek letter mu for micro.
+    fn symbols(self) -> Vec<&'static str> {
+        match self {
+            Self::Micro => vec!["µ", "μ", "u"],
+            prefix => vec![prefix.symbol()],
+        }
+    }
+
+    /// Returns the words accepted for this prefix.
+    fn names(self) -> Vec<&'static str> {
+        match self {
+            Self::Deca => vec!["deca", "deka"],
+            prefix => vec![prefix.name()],
+        }
+    }
+}
+
+impl fmt::Display for Prefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Prefix {
+    /// Serializes the prefix by its name, e.g. "kilo".
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Prefix {
+    /// Accepts a prefix name in any case or an exact symbol, e.g. "kilo" or "k".
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        let lower = text.to_lowercase();
+        Prefix::all()
+            .iter()
+            .copied()
+            .find(|prefix| prefix.names().contains(&lower.as_str()) || prefix.symbols().contains(&text.as_str()))
+            .ok_or_else(|| serde::de::Error::custom(format!("Unknown SI prefix: '{text}'")))
+    }
+}
+
+impl Unit {
+    /// Returns true if this is a metric unit that accepts SI prefixes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::Unit;
+    ///
+    /// assert!(Unit::Liters.accepts_prefixes());
+    /// assert!(!Unit::Gallons.accepts_prefixes());
+    /// ```
+    #[must_use]
+    pub const fn accepts_prefixes(self) -> bool {
+        matches!(
+            self,
+            Self::Kelvin
+                | Self::Liters
+                | Self::Meters
+                | Self::Grams
+                | Self::Seconds
+                | Self::Joules
+                | Self::Watts
+                | Self::Pascals
+                | Self::Bars
+                | Self::Bits
+                | Self::Bytes
+        )
+    }
+}
+
+/// A unit with an optional SI prefix, such as kiloliters or microseconds.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{Prefix, PrefixedUnit, Unit};
+///
+/// let unit: PrefixedUnit = "kilolitres".parse().unwrap();
+/// assert_eq!(unit, PrefixedUnit::new(Prefix::Kilo, Unit::Liters).unwrap());
+/// assert_eq!(unit.symbol(), "kL");
+///
+/// let milliliters: PrefixedUnit = "mL".parse().unwrap();
+/// assert_eq!(unit.convert(milliliters, 2.0), Some(2_000_000.0));
+///
+/// assert!("kgal".parse::<PrefixedUnit>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrefixedUnit {
+    prefix: Option<Prefix>,
+    unit: Unit,
+}
+
+impl PrefixedUnit {
+    /// Applies a prefix to a metric unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitParseError::PrefixNotAllowed`] if the unit is not metric.
+    pub fn new(prefix: Prefix, unit: Unit) -> Result<Self, UnitParseError> {
+        if unit.accepts_prefixes() {
+            Ok(Self { prefix: Some(prefix), unit })
+        } else {
+            Err(UnitParseError::PrefixNotAllowed { prefix, unit })
+        }
+    }
+
+    /// Parses a unit with an optional SI prefix, resolving bare volume names
+    /// such as "gallon" in the given system, like [`Unit::from_str_with`].
+    ///
+    /// Exact unit symbols (such as `mL` or `MB`) are tried first, then an
+    /// upper-case prefix symbol before an exact unit symbol (`ML`, `GW`),
+    /// then every unit name and alias, then prefix words (`kilolitre`), and
+    /// finally lower-case prefix symbols (`kL`, `µs`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitParseError::UnknownUnit`] if the name is not recognized,
+    /// [`UnitParseError::PrefixNotAllowed`] for a prefixed non-metric unit, or
+    /// [`UnitParseError::AmbiguousDataSize`] for a byte symbol written with a
+    /// lower-case "b".
+    pub fn from_str_with(s: &str, system: VolumeSystem) -> Result<Self, UnitParseError> {
+        let text = s.trim();
+        if let Some(unit) = Unit::all().iter().copied().find(|unit| unit.symbol() == text) {
+            return Ok(in_system(unit, system).into());
+        }
+        if let Some(prefixed) = split_symbol(text, true, system) {
+            return prefixed;
+        }
+        match parse_builtin(text, system) {
+            Ok(unit) => return Ok(unit.into()),
+            Err(err @ UnitParseError::AmbiguousDataSize { .. }) => return Err(err),
+            Err(_) => {}
+        }
+        split_name(text, system)
+            .or_else(|| split_symbol(text, false, system))
+            .unwrap_or_else(|| Err(UnitParseError::UnknownUnit(s.to_string())))
+    }
+
+    /// Returns the prefix, if any.
+    #[must_use]
+    pub const fn prefix(self) -> Option<Prefix> {
+        self.prefix
+    }
+
+    /// Returns the built-in unit this names, such as [`Unit::Kilograms`] for
+    /// kilo + grams, or `None` for a prefixed unit that is not built in.
+    pub(crate) fn builtin(self) -> Option<Unit> {
+        match self.prefix {
+            None => Some(self.unit),
+            Some(_) => Unit::all().iter().copied().find(|unit| unit.symbol() == self.symbol()),
+        }
+    }
+
+    /// Returns the unit the prefix applies to.
+    #[must_use]
+    pub const fn unit(self) -> Unit {
+        self.unit
+    }
+
+    /// Returns the physical dimension this unit measures.
+    #[must_use]
+    pub const fn dimension(self) -> Dimension {
+        self.unit.dimension()
+    }
+
+    /// Returns the factor relating this unit to its dimension's base unit,
+    /// including the prefix; see [`Unit::scale`].
+    #[must_use]
+    pub fn scale(self) -> f64 {
+        match self.prefix {
+            Some(prefix) => prefix.factor() * self.unit.scale(),
+            None => self.unit.scale(),
+        }
+    }
+
+    /// Returns the symbol, e.g. "kL" or "µs".
+    #[must_use]
+    pub fn symbol(self) -> String {
+        match self.prefix {
+            Some(prefix) => format!("{}{}", prefix.symbol(), self.unit.symbol()),
+            None => self.unit.symbol().to_string(),
+        }
+    }
+
+    /// Returns the display name, singular or plural, e.g. "kiloliters".
+    #[must_use]
+    pub fn name(self, plural: bool) -> String {
+        match self.prefix {
+            Some(prefix) => format!("{}{}", prefix.name(), self.unit.name(plural)),
+            None => self.unit.name(plural).to_string(),
+        }
+    }
+
+    /// Converts a value from this unit into another unit of the same dimension.
+    ///
+    /// Unprefixed units convert exactly as [`convert`](crate::convert) does.
+    /// Returns `None` if the units measure different dimensions.
+    #[must_use]
+    pub fn convert(self, to: PrefixedUnit, value: f64) -> Option<f64> {
+        match (self.prefix, to.prefix) {
+            (None, None) => convert(self.unit, to.unit, value),
+            _ if self.dimension() != to.dimension() => None,
+            _ => {
+                // Prefixed units are metric and have no offset, so only the
+                // unprefixed side (e.g. Celsius) can contribute one.
+                let base = value * self.scale() + self.unit.offset();
+                Some((base - to.unit.offset()) / to.scale())
+            }
+        }
+    }
+}
+
+impl From<Unit> for PrefixedUnit {
+    fn from(unit: Unit) -> Self {
+        Self { prefix: None, unit }
+    }
+}
+
+impl fmt::Display for PrefixedUnit {
+    /// Formats the unit by its plural name, e.g. "kiloliters".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name(true))
+    }
+}
+
+impl FromStr for PrefixedUnit {
+    type Err = UnitParseError;
+
+    /// Parses a unit with an optional SI prefix, as
+    /// [`PrefixedUnit::from_str_with`] does in [`VolumeSystem::UsCustomary`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with(s, VolumeSystem::UsCustomary)
+    }
+}
+
+/// Splits a prefix symbol off the front of `text`.
+///
+/// With `exact_unit`, only upper-case prefixes followed by the exact symbol
+/// of a unit that accepts prefixes are taken; otherwise the rest may be any spelling of the unit.
+/// Returns `None` if no prefix and unit split is possible.
+fn split_symbol(text: &str, exact_unit: bool, system: VolumeSystem) -> Option<Result<PrefixedUnit, UnitParseError>> {
+    let mut prefixes: Vec<(Prefix, &str)> = Prefix::all()
+        .iter()
+        .flat_map(|&prefix| prefix.symbols().into_iter().map(move |symbol| (prefix, symbol)))
+        .filter(|(_, symbol)| !exact_unit || symbol.starts_with(|c: char| c.is_ascii_uppercase()))
+        .collect();
+    prefixes.sort_by_key(|(_, symbol)| std::cmp::Reverse(symbol.len()));
+
+    prefixes.into_iter().find_map(|(prefix, symbol)| {
+        let rest = text.strip_prefix(symbol)?;
+        let exact = Unit::all().iter().copied().find(|unit| unit.symbol() == rest);
+        let unit = if exact_unit {
+            exact.filter(|unit| unit.accepts_prefixes())?
+        } else {
+            exact.or_else(|| parse_builtin(rest, system).ok())?
+        };
+        Some(PrefixedUnit::new(prefix, unit))
+    })
+}
+
+/// Splits a prefix word off the front of `text`, as in "kilolitre".
+fn split_name(text: &str, system: VolumeSystem) -> Option<Result<PrefixedUnit, UnitParseError>> {
+    let lower = text.to_lowercase();
+    Prefix::all().iter().find_map(|&prefix| {
+        prefix.names().into_iter().find_map(|name| {
+            let rest = lower.strip_prefix(name)?.trim_start_matches(['-', ' ']);
+            let unit = parse_builtin(rest, system).ok()?;
+            Some(PrefixedUnit::new(prefix, unit))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+    use crate::{TOLERANCE_EPSILON, TOLERANCE_ULPS};
+
+    fn parse(s: &str) -> PrefixedUnit {
+        s.parse().unwrap_or_else(|err| panic!("{s}: {err}"))
+    }
+
+    fn prefixed(prefix: Prefix, unit: Unit) -> PrefixedUnit {
+        PrefixedUnit::new(prefix, unit).unwrap()
+    }
+
+    #[test]
+    // test prefix symbols and words on metric units
+    fn test_parse_prefixed() {
+        assert_eq!(parse("kL"), prefixed(Prefix::Kilo, Unit::Liters));
+        assert_eq!(parse("kilolitre"), prefixed(Prefix::Kilo, Unit::Liters));
+        assert_eq!(parse("µL"), prefixed(Prefix::Micro, Unit::Liters));
+        assert_eq!(parse("uL"), prefixed(Prefix::Micro, Unit::Liters));
+        assert_eq!(parse("microliters"), prefixed(Prefix::Micro, Unit::Liters));
+        assert_eq!(parse("dam"), prefixed(Prefix::Deca, Unit::Meters));
+        assert_eq!(parse("dekameters"), prefixed(Prefix::Deca, Unit::Meters));
+        assert_eq!(parse("ns"), prefixed(Prefix::Nano, Unit::Seconds));
+        assert_eq!(parse("GW"), prefixed(Prefix::Giga, Unit::Watts));
+        assert_eq!(parse("hPa"), prefixed(Prefix::Hecto, Unit::Pascals));
+        assert_eq!(parse("millibar"), prefixed(Prefix::Milli, Unit::Bars));
+        assert_eq!(parse("Ym"), prefixed(Prefix::Yotta, Unit::Meters));
+        assert_eq!(parse("ym"), prefixed(Prefix::Yocto, Unit::Meters));
+    }
+
+    #[test]
+    // test prefix symbols are case-sensitive and built-in units keep their variants
+    fn test_parse_prefixed_case() {
+        assert_eq!(parse("mL"), Unit::Milliliters.into());
+        assert_eq!(parse("ML"), prefixed(Prefix::Mega, Unit::Liters));
+        assert_eq!(parse("mg"), Unit::Milligrams.into());
+        assert_eq!(parse("Mg"), prefixed(Prefix::Mega, Unit::Grams));
+        assert_eq!(parse("MB"), Unit::Megabytes.into());
+        assert_eq!(parse("gallons"), Unit::Gallons.into());
+    }
+
+    #[test]
+    // test a capitalized name is not split into a prefix and a non-metric symbol
+    fn test_parse_prefixed_non_metric_symbol() {
+        assert_eq!(parse("Min"), Unit::Minutes.into());
+        assert_eq!(parse("Yd"), Unit::Yards.into());
+        assert_eq!(parse("Ms"), prefixed(Prefix::Mega, Unit::Seconds));
+    }
+
+    #[test]
+    // test bare volume names and symbols resolve in the requested system
+    fn test_parse_prefixed_volume_system() {
+        let imperial = |s| PrefixedUnit::from_str_with(s, VolumeSystem::Imperial);
+        assert_eq!(imperial("gal"), Ok(Unit::ImperialGallons.into()));
+        assert_eq!(imperial("pints"), Ok(Unit::ImperialPints.into()));
+        assert_eq!(imperial("US gal"), Ok(Unit::Gallons.into()));
+        assert_eq!(imperial("kL"), Ok(prefixed(Prefix::Kilo, Unit::Liters)));
+        assert_eq!(parse("gal"), Unit::Gallons.into());
+    }
+
+    #[test]
+    // test prefixes on non-metric units are rejected
+    fn test_parse_prefixed_rejects() {
+        assert_eq!(
+            "kgal".parse::<PrefixedUnit>(),
+            Err(UnitParseError::PrefixNotAllowed { prefix: Prefix::Kilo, unit: Unit::Gallons })
+        );
+        assert_eq!(
+            "millifeet".parse::<PrefixedUnit>(),
+            Err(UnitParseError::PrefixNotAllowed { prefix: Prefix::Milli, unit: Unit::Feet })
+        );
+        assert!(matches!("kfoo".parse::<PrefixedUnit>(), Err(UnitParseError::UnknownUnit(_))));
+        assert!(PrefixedUnit::new(Prefix::Kilo, Unit::Cups).is_err());
+        assert_eq!(
+            UnitParseError::PrefixNotAllowed { prefix: Prefix::Kilo, unit: Unit::Gallons }.to_string(),
+            "Unit 'gallons' does not accept the SI prefix 'kilo'"
+        );
+        // Plain unit parsing is unchanged
+        assert!(Unit::from_str("ks").is_err());
+        assert_eq!(parse("ks"), prefixed(Prefix::Kilo, Unit::Seconds));
+    }
+
+    #[test]
+    // test prefixed conversions within and across prefixes
+    fn test_convert_prefixed() {
+        let cases = [
+            ("kL", "mL", 2.0, 2_000_000.0),
+            ("µL", "mL", 1500.0, 1.5),
+            ("ML", "gal", 1.0, 264_172.052_4),
+            ("Mg", "kg", 1.0, 1000.0),
+            ("mK", "°C", 273_150.0, 0.0),
+            ("ns", "s", 1.0, 1e-9),
+            ("MW", "hp", 1.0, 1341.0221),
+            ("Gbit", "MB", 8.0, 1000.0),
+        ];
+        for (from, to, value, expected) in cases {
+            let result = parse(from).convert(parse(to), value).unwrap();
+            assert!(approx_eq!(f64, result, expected, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{from} -> {to}: {result}");
+        }
+        // Unprefixed units convert exactly as convert does
+        assert_eq!(parse("liters").convert(parse("gallons"), 1.0), Some(0.2641));
+        assert_eq!(parse("kL").convert(parse("kg"), 1.0), None);
+    }
+
+    #[test]
+    // test symbols, names, and factors
+    fn test_prefix_metadata() {
+        assert_eq!(Prefix::all().len(), 20);
+        assert_eq!(Prefix::Milli.factor(), 0.001);
+        assert_eq!(Prefix::Kilo.factor(), 1000.0);
+        assert_eq!(Prefix::Yotta.factor(), 1e24);
+        assert_eq!(parse("µL").symbol(), "µL");
+        assert_eq!(parse("kilolitre").to_string(), "kiloliters");
+        assert_eq!(parse("kilolitre").name(false), "kiloliter");
+    }
+}
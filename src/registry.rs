@@ -0,0 +1,928 @@
+//! A runtime-extensible set of units.
+//!
+//! [`UnitRegistry`] starts with every built-in [`Unit`] and can load extra
+//! unit definitions at runtime, so teachers can add units such as gills or
+//! the Leiden scale without recompiling. Each definition relates the new
+//! unit to its dimension's [base unit](Dimension::base_unit):
+//!
+//! ```text
+//! value in base unit = value × scale + offset
+//! ```
+//!
+//! # Definitions file format
+//!
+//! The plain-text format has one unit per line, with `|`-separated fields
+//! `name | dimension | scale | offset | aliases`. The offset and aliases are
+//! optional, aliases are comma-separated, and `#` starts a comment:
+//!
+//! ```text
+//! # name    | dimension   | scale       | offset | aliases
+//! gill      | volume      | 0.11829411825 | 0    | gi, gills
+//! leiden    | temperature | 1           | 20.15  | ld
+//! ```
+//!
+//! # Ratio units
+//!
+//! Word problems often use ad-hoc rates: a batch of cookies takes 2½ cups of
+//! flour, a leaky faucet loses 0.05 mL per drip. A [`RatioUnit`]
+//! relates a custom counting unit to an amount of a built-in unit, and the
+//! registry converts counting units like any other: to units of the same
+//! dimension (batches → cups), from them (grams → batches), and to other
+//! counting units that share a dimension (batches → drips). A counting unit
+//! may have one ratio per dimension, so a batch can also take 45 minutes.
+//!
+//! In the plain-text format, a ratio line has `ratio` in place of the
+//! dimension, then the amount and unit per one counting unit:
+//!
+//! ```text
+//! # name    | ratio | amount | unit    | aliases
+//! batch     | ratio | 2.5    | cups    | batches
+//! batch     | ratio | 45     | minutes
+//! ```
+//!
+//! With the `toml` feature, unit definitions can also be written as TOML:
+//!
+//! ```toml
+//! [[unit]]
+//! name = "gill"
+//! dimension = "volume"
+//! scale = 0.11829411825
+//! aliases = ["gi"]
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::parse::{closest, normalize};
+use crate::{convert, Dimension, PrefixedUnit, Unit, VolumeSystem};
+
+/// A unit defined at runtime, relative to its dimension's base unit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitDefinition {
+    /// The unit's name
+    pub name: String,
+    /// Other names accepted for the unit
+    pub aliases: Vec<String>,
+    /// The dimension the unit measures
+    pub dimension: Dimension,
+    /// Base units per one of this unit
+    pub scale: f64,
+    /// Base-unit value of zero in this unit (non-zero only for temperature scales)
+    pub offset: f64,
+}
+
+impl UnitDefinition {
+    /// Creates a definition with no aliases and no offset.
+    #[must_use]
+    pub fn new(name: &str, dimension: Dimension, scale: f64) -> Self {
+        Self {
+            name: name.to_string(),
+            aliases: Vec::new(),
+            dimension,
+            scale,
+            offset: 0.0,
+        }
+    }
+
+    /// Sets the offset from the base unit.
+    #[must_use]
+    pub fn with_offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Adds an alias.
+    #[must_use]
+    pub fn with_alias(mut self, alias: &str) -> Self {
+        self.aliases.push(alias.to_string());
+        self
+    }
+
+    /// Returns the name and every alias.
+    fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+}
+
+/// A counting unit defined by its ratio to a built-in unit, such as a batch
+/// that takes 2.5 cups.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{RatioUnit, Unit, UnitRegistry};
+///
+/// let mut registry = UnitRegistry::new();
+/// registry.define_ratio(RatioUnit::new("batch", 2.5, Unit::Cups)).unwrap();
+/// registry.define_ratio(RatioUnit::new("batch", 45.0, Unit::Minutes)).unwrap();
+///
+/// // How long to bake through 10 cups of batter?
+/// let batches = registry.convert("cups", "batches", 10.0).unwrap();
+/// assert_eq!(batches, 4.0);
+/// assert_eq!(registry.convert("batches", "hours", batches).unwrap(), 3.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RatioUnit {
+    /// The counting unit's name
+    pub name: String,
+    /// Other names accepted for the counting unit
+    pub aliases: Vec<String>,
+    /// Amount of `unit` per one counting unit
+    pub amount: f64,
+    /// The built-in unit the counting unit is related to
+    pub unit: Unit,
+}
+
+impl RatioUnit {
+    /// Creates a ratio of `amount` of `unit` per one counting unit, with no
+    /// aliases.
+    #[must_use]
+    pub fn new(name: &str, amount: f64, unit: Unit) -> Self {
+        Self {
+            name: name.to_string(),
+            aliases: Vec::new(),
+            amount,
+            unit,
+        }
+    }
+
+    /// Adds an alias.
+    #[must_use]
+    pub fn with_alias(mut self, alias: &str) -> Self {
+        self.aliases.push(alias.to_string());
+        self
+    }
+
+    /// Returns the name and every alias.
+    fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+}
+
+/// Error type for registry operations.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegistryError {
+    /// No built-in or registered unit has this name.
+    UnknownUnit(String),
+    /// The units measure different dimensions.
+    DimensionMismatch {
+        /// Dimension of the unit converted from
+        from: Dimension,
+        /// Dimension of the unit converted to
+        to: Dimension,
+    },
+    /// No ratio relates a counting unit to the other unit's dimension.
+    NoRatio {
+        /// Name of the unit converted from
+        from: String,
+        /// Name of the unit converted to
+        to: String,
+    },
+    /// A counting unit was used where a unit with a single dimension is needed.
+    CountingUnit(String),
+    /// A definition reuses a name that already belongs to another unit.
+    DuplicateName(String),
+    /// A definition's scale is zero or not finite, or its offset is not finite.
+    InvalidDefinition(String),
+    /// A definitions file could not be parsed.
+    Syntax {
+        /// One-based line number (zero if unknown)
+        line: usize,
+        /// Description of the problem
+        message: String,
+    },
+    /// A definitions file could not be read.
+    Io(String),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownUnit(unit) => write!(f, "Unknown unit: '{unit}'"),
+            Self::DimensionMismatch { from, to } => {
+                write!(f, "Cannot convert {from} to {to}")
+            }
+            Self::NoRatio { from, to } => write!(f, "No ratio relates '{from}' to '{to}'"),
+            Self::CountingUnit(name) => write!(f, "'{name}' is a counting unit with no single dimension"),
+            Self::DuplicateName(name) => write!(f, "Unit name already defined: '{name}'"),
+            Self::InvalidDefinition(name) => write!(f, "Invalid scale or offset for unit '{name}'"),
+            Self::Syntax { line, message } => write!(f, "Line {line}: {message}"),
+            Self::Io(message) => write!(f, "Cannot read definitions: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// A unit found in a [`UnitRegistry`], built-in or runtime-defined.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Resolved<'a> {
+    BuiltIn(Unit),
+    /// An SI-prefixed unit that is not built in, such as "ML".
+    Prefixed(PrefixedUnit),
+    Defined(&'a UnitDefinition),
+    /// A counting unit, by the name of its first ratio.
+    Counting(&'a str),
+}
+
+impl Resolved<'_> {
+    /// Returns the unit's dimension, or `None` for a counting unit.
+    fn dimension(&self) -> Option<Dimension> {
+        match self {
+            Self::BuiltIn(unit) => Some(unit.dimension()),
+            Self::Prefixed(unit) => Some(unit.dimension()),
+            Self::Defined(definition) => Some(definition.dimension),
+            Self::Counting(_) => None,
+        }
+    }
+}
+
+/// The built-in units plus any units defined at runtime.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::UnitRegistry;
+///
+/// let mut registry = UnitRegistry::new();
+/// registry.load_str("gill | volume | 0.11829411825 | 0 | gi").unwrap();
+///
+/// let cups = registry.convert("gills", "cups", 2.0).unwrap();
+/// assert!((cups - 1.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UnitRegistry {
+    definitions: Vec<UnitDefinition>,
+    ratios: Vec<RatioUnit>,
+    volume_system: VolumeSystem,
+}
+
+impl UnitRegistry {
+    /// Creates a registry containing only the built-in units.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves bare volume names such as "gallon" in the given system, in
+    /// lookups and in the ratio lines of definitions files loaded later.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::{UnitRegistry, VolumeSystem};
+    ///
+    /// let registry = UnitRegistry::new().with_volume_system(VolumeSystem::Imperial);
+    /// let fluid_ounces = registry.convert("pints", "fl oz", 1.0).unwrap();
+    /// assert!((fluid_ounces - 20.0).abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn with_volume_system(mut self, system: VolumeSystem) -> Self {
+        self.volume_system = system;
+        self
+    }
+
+    /// Returns the volume system bare volume names resolve in.
+    #[must_use]
+    pub fn volume_system(&self) -> VolumeSystem {
+        self.volume_system
+    }
+
+    /// Returns the runtime-defined units, in the order they were added.
+    #[must_use]
+    pub fn definitions(&self) -> &[UnitDefinition] {
+        &self.definitions
+    }
+
+    /// Returns the ratio units, in the order they were added.
+    #[must_use]
+    pub fn ratios(&self) -> &[RatioUnit] {
+        &self.ratios
+    }
+
+    /// Returns true if `name` names a built-in or registered unit, including
+    /// counting units.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.lookup(name).is_ok()
+    }
+
+    /// Returns built-in and registered unit names close to an unrecognized
+    /// name, best first, like
+    /// [`UnitParseError::suggestions`](crate::UnitParseError::suggestions).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::UnitRegistry;
+    ///
+    /// let mut registry = UnitRegistry::new();
+    /// registry.load_str("firkin | volume | 40.91481 | 0").unwrap();
+    /// assert_eq!(registry.suggestions("frikin"), vec!["firkin"]);
+    /// ```
+    #[must_use]
+    pub fn suggestions(&self, name: &str) -> Vec<String> {
+        let built_in = Unit::all().iter().map(|unit| unit.aliases().to_vec());
+        let defined = self.definitions.iter().map(|definition| definition.names().collect());
+        // A counting unit with ratios in several dimensions is suggested once
+        let mut counting: Vec<Vec<&str>> = Vec::new();
+        for ratio in &self.ratios {
+            match counting.iter_mut().find(|names| names[0] == ratio.name) {
+                Some(names) => names.extend(ratio.aliases.iter().map(String::as_str)),
+                None => counting.push(ratio.names().collect()),
+            }
+        }
+        closest(name, built_in.chain(defined).chain(counting))
+    }
+
+    /// Returns the dimension of the unit named `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::UnknownUnit`] if no unit has that name, or
+    /// [`RegistryError::CountingUnit`] if it names a counting unit.
+    pub fn dimension(&self, name: &str) -> Result<Dimension, RegistryError> {
+        self.resolve(name)?.dimension().ok_or_else(|| RegistryError::CountingUnit(name.to_string()))
+    }
+
+    /// Adds a unit definition.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::DuplicateName`] if the name or an alias is
+    /// already taken, or [`RegistryError::InvalidDefinition`] if the scale is
+    /// zero or either number is not finite.
+    pub fn define(&mut self, definition: UnitDefinition) -> Result<(), RegistryError> {
+        let valid = definition.scale.is_finite()
+            && definition.scale != 0.0
+            && definition.offset.is_finite();
+        if !valid {
+            return Err(RegistryError::InvalidDefinition(definition.name));
+        }
+        let mut seen = Vec::new();
+        for name in definition.names() {
+            let normalized = normalize(name);
+            if normalized.is_empty() || self.contains(name) || seen.contains(&normalized) {
+                return Err(RegistryError::DuplicateName(name.to_string()));
+            }
+            seen.push(normalized);
+        }
+        self.definitions.push(definition);
+        Ok(())
+    }
+
+    /// Adds a ratio unit, creating its counting unit or adding a ratio in
+    /// another dimension to an existing one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::DuplicateName`] if a name belongs to a
+    /// non-counting unit or the counting unit already has a ratio in the
+    /// unit's dimension, or [`RegistryError::InvalidDefinition`] if the
+    /// amount is zero or not finite or the unit is a temperature.
+    pub fn define_ratio(&mut self, ratio: RatioUnit) -> Result<(), RegistryError> {
+        let valid = ratio.amount.is_finite() && ratio.amount != 0.0 && ratio.unit.dimension() != Dimension::Temperature;
+        if !valid {
+            return Err(RegistryError::InvalidDefinition(ratio.name));
+        }
+        // Later ratios for the same counting unit are stored under its first name
+        let counting = match self.lookup(&ratio.name) {
+            Ok(Resolved::Counting(counting)) => Some(counting.to_string()),
+            _ => None,
+        };
+        let mut seen = Vec::new();
+        for name in ratio.names() {
+            let normalized = normalize(name);
+            let taken = match self.lookup(name) {
+                Ok(Resolved::Counting(other)) => {
+                    Some(other) != counting.as_deref()
+                        || self.ratios.iter().any(|existing| {
+                            existing.name == other && existing.unit.dimension() == ratio.unit.dimension()
+                        })
+                }
+                Ok(_) => true,
+                Err(_) => false,
+            };
+            if normalized.is_empty() || taken || seen.contains(&normalized) {
+                return Err(RegistryError::DuplicateName(name.to_string()));
+            }
+            seen.push(normalized);
+        }
+        let name = counting.unwrap_or(ratio.name);
+        self.ratios.push(RatioUnit { name, ..ratio });
+        Ok(())
+    }
+
+    /// Loads definitions in the plain-text format described in the
+    /// [module documentation](self), returning how many were added.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::Syntax`] for a malformed line, or any error
+    /// from [`UnitRegistry::define`]. Definitions before the failing line
+    /// are kept.
+    pub fn load_str(&mut self, text: &str) -> Result<usize, RegistryError> {
+        let mut added = 0;
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let definition = parse_line(line, self.volume_system).map_err(|message| RegistryError::Syntax {
+                line: index + 1,
+                message,
+            })?;
+            match definition {
+                Definition::Unit(definition) => self.define(definition)?,
+                Definition::Ratio(ratio) => self.define_ratio(ratio)?,
+            }
+            added += 1;
+        }
+        Ok(added)
+    }
+
+    /// Loads definitions written as TOML `[[unit]]` and `[[ratio]]` tables,
+    /// returning how many were added.
+    ///
+    /// `[[unit]]` tables have the fields of a definitions line (`name`,
+    /// `dimension`, `scale`, and optionally `offset` and `aliases`), and
+    /// `[[ratio]]` tables those of a ratio line (`name`, `amount`, `unit`,
+    /// and optionally `aliases`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::UnitRegistry;
+    ///
+    /// let mut registry = UnitRegistry::new();
+    /// let text = r#"
+    ///     [[ratio]]
+    ///     name = "batch"
+    ///     amount = 2.5
+    ///     unit = "cups"
+    /// "#;
+    /// assert_eq!(registry.load_toml_str(text), Ok(1));
+    /// assert_eq!(registry.convert("cups", "batches", 10.0).unwrap(), 4.0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::Syntax`] if the document is not valid TOML or
+    /// a table is missing a field, or any error from [`UnitRegistry::define`]
+    /// or [`UnitRegistry::define_ratio`].
+    #[cfg(feature = "toml")]
+    pub fn load_toml_str(&mut self, text: &str) -> Result<usize, RegistryError> {
+        let document: toml::Table = text.parse().map_err(|err: toml::de::Error| toml_syntax(err.message()))?;
+        let tables = |key: &str| match document.get(key) {
+            None => Ok(&[][..]),
+            Some(tables) => tables
+                .as_array()
+                .map(Vec::as_slice)
+                .ok_or_else(|| toml_syntax(&format!("'{key}' must be an array of tables"))),
+        };
+
+        let mut added = 0;
+        for unit in tables("unit")? {
+            let dimension = toml_str(unit, "dimension")?;
+            let dimension = parse_dimension(dimension).map_err(|message| toml_syntax(&message))?;
+            let mut definition = UnitDefinition::new(toml_str(unit, "name")?, dimension, toml_number(unit, "scale")?);
+            if unit.get("offset").is_some() {
+                definition.offset = toml_number(unit, "offset")?;
+            }
+            definition.aliases = toml_aliases(unit)?;
+            self.define(definition)?;
+            added += 1;
+        }
+        for ratio in tables("ratio")? {
+            let unit = Unit::from_str_with(toml_str(ratio, "unit")?, self.volume_system)
+                .map_err(|err| toml_syntax(&err.to_string()))?;
+            let definition = RatioUnit {
+                aliases: toml_aliases(ratio)?,
+                ..RatioUnit::new(toml_str(ratio, "name")?, toml_number(ratio, "amount")?, unit)
+            };
+            self.define_ratio(definition)?;
+            added += 1;
+        }
+        Ok(added)
+    }
+
+    /// Loads a definitions file, returning how many units were added.
+    ///
+    /// Files ending in `.toml` are read as TOML (with the `toml` feature);
+    /// anything else is read as plain text.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::Io`] if the file cannot be read, or any error
+    /// from loading its contents.
+    pub fn load_file(&mut self, path: impl AsRef<Path>) -> Result<usize, RegistryError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|err| RegistryError::Io(format!("{}: {err}", path.display())))?;
+        #[cfg(feature = "toml")]
+        if path.extension().is_some_and(|extension| extension == "toml") {
+            return self.load_toml_str(&text);
+        }
+        self.load_str(&text)
+    }
+
+    /// Converts a value between two units named by any accepted name.
+    ///
+    /// Conversions between two built-in units give exactly the same result
+    /// as [`convert`]; anything involving a runtime-defined unit goes through
+    /// the dimension's base unit. Counting units convert through their ratio
+    /// in the other unit's dimension, or between two counting units through
+    /// any dimension both have a ratio in.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::UnknownUnit`] if either name is unknown,
+    /// [`RegistryError::DimensionMismatch`] if the units measure different
+    /// dimensions, or [`RegistryError::NoRatio`] if no ratio relates a
+    /// counting unit to the other unit.
+    pub fn convert(&self, from: &str, to: &str, value: f64) -> Result<f64, RegistryError> {
+        let (from_name, to_name) = (from, to);
+        let from = self.resolve(from_name)?;
+        let to = self.resolve(to_name)?;
+        if let (Resolved::BuiltIn(from), Resolved::BuiltIn(to)) = (from, to) {
+            if let Some(result) = convert(from, to, value) {
+                return Ok(result);
+            }
+        }
+        let both = |dimension| Some((self.scale_offset(from, dimension)?, self.scale_offset(to, dimension)?));
+        let found = match (from.dimension(), to.dimension()) {
+            (Some(from), Some(to)) if from != to => return Err(RegistryError::DimensionMismatch { from, to }),
+            (Some(dimension), _) | (None, Some(dimension)) => both(dimension),
+            (None, None) => Dimension::all().iter().find_map(|&dimension| both(dimension)),
+        };
+        let Some(((from_scale, from_offset), (to_scale, to_offset))) = found else {
+            return Err(RegistryError::NoRatio { from: from_name.to_string(), to: to_name.to_string() });
+        };
+        Ok((value * from_scale + from_offset - to_offset) / to_scale)
+    }
+
+    /// Returns a unit's scale and offset relative to the base unit of
+    /// `dimension`, or `None` if it does not measure that dimension.
+    fn scale_offset(&self, unit: Resolved<'_>, dimension: Dimension) -> Option<(f64, f64)> {
+        match unit {
+            Resolved::BuiltIn(unit) if unit.dimension() == dimension => Some((unit.scale(), unit.offset())),
+            Resolved::Prefixed(unit) if unit.dimension() == dimension => Some((unit.scale(), unit.unit().offset())),
+            Resolved::Defined(definition) if definition.dimension == dimension => {
+                Some((definition.scale, definition.offset))
+            }
+            Resolved::Counting(name) => self
+                .ratios
+                .iter()
+                .find(|ratio| ratio.name == name && ratio.unit.dimension() == dimension)
+                .map(|ratio| (ratio.amount * ratio.unit.scale(), 0.0)),
+            _ => None,
+        }
+    }
+
+    /// Finds a unit by name, preferring built-in units, then registered
+    /// units, then SI-prefixed units read as [`PrefixedUnit`] reads them.
+    fn resolve(&self, name: &str) -> Result<Resolved<'_>, RegistryError> {
+        self.lookup(name).or_else(|err| {
+            PrefixedUnit::from_str_with(name, self.volume_system).map(Resolved::Prefixed).map_err(|_| err)
+        })
+    }
+
+    /// Finds a built-in or registered unit by name. Prefixed units are left
+    /// out, so that a name such as "hL" can be registered even though it
+    /// also reads as a prefix and a unit.
+    fn lookup(&self, name: &str) -> Result<Resolved<'_>, RegistryError> {
+        if let Ok(unit) = Unit::from_str_with(name, self.volume_system) {
+            return Ok(Resolved::BuiltIn(unit));
+        }
+        let normalized = normalize(name);
+        let matches = |candidate: &str| {
+            let candidate = normalize(candidate);
+            candidate == normalized
+                || normalized.strip_suffix('s').is_some_and(|stem| stem == candidate)
+                || normalized.strip_suffix("es").is_some_and(|stem| stem == candidate)
+        };
+        let defined = self.definitions.iter().find(|definition| definition.names().any(matches));
+        let counting = || self.ratios.iter().find(|ratio| ratio.names().any(matches));
+        defined
+            .map(Resolved::Defined)
+            .or_else(|| counting().map(|ratio| Resolved::Counting(ratio.name.as_str())))
+            .ok_or_else(|| RegistryError::UnknownUnit(name.to_string()))
+    }
+}
+
+/// Parses a dimension name such as "volume".
+fn parse_dimension(name: &str) -> Result<Dimension, String> {
+    let normalized = normalize(name);
+    Dimension::all()
+        .iter()
+        .copied()
+        .find(|dimension| dimension.name() == normalized)
+        .ok_or_else(|| format!("unknown dimension '{name}'"))
+}
+
+/// A parsed line of a definitions file.
+enum Definition {
+    Unit(UnitDefinition),
+    Ratio(RatioUnit),
+}
+
+/// Parses one `name | dimension | scale | offset | aliases` or
+/// `name | ratio | amount | unit | aliases` line.
+fn parse_line(line: &str, system: VolumeSystem) -> Result<Definition, String> {
+    let fields: Vec<&str> = line.split('|').map(str::trim).collect();
+    if !(3..=5).contains(&fields.len()) {
+        return Err("expected 'name | dimension | scale | offset | aliases'".to_string());
+    }
+    if fields[0].is_empty() {
+        return Err("missing unit name".to_string());
+    }
+    let number = |field: &str| field.parse::<f64>().map_err(|_| format!("invalid number '{field}'"));
+    let aliases = || -> Vec<String> {
+        fields.get(4).map_or_else(Vec::new, |aliases| {
+            aliases
+                .split(',')
+                .map(str::trim)
+                .filter(|alias| !alias.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+    };
+
+    if normalize(fields[1]) == "ratio" {
+        let unit = fields.get(3).ok_or("expected 'name | ratio | amount | unit | aliases'")?;
+        let unit = Unit::from_str_with(unit, system).map_err(|err| err.to_string())?;
+        let ratio = RatioUnit { aliases: aliases(), ..RatioUnit::new(fields[0], number(fields[2])?, unit) };
+        return Ok(Definition::Ratio(ratio));
+    }
+
+    let mut definition = UnitDefinition::new(fields[0], parse_dimension(fields[1])?, number(fields[2])?);
+    if let Some(offset) = fields.get(3).filter(|field| !field.is_empty()) {
+        definition.offset = number(offset)?;
+    }
+    definition.aliases = aliases();
+    Ok(Definition::Unit(definition))
+}
+
+/// Returns the syntax error for a malformed TOML document, which has no
+/// line number.
+#[cfg(feature = "toml")]
+fn toml_syntax(message: &str) -> RegistryError {
+    RegistryError::Syntax { line: 0, message: message.to_string() }
+}
+
+/// Returns the field `key` of a TOML table.
+#[cfg(feature = "toml")]
+fn toml_field<'a>(table: &'a toml::Value, key: &str) -> Result<&'a toml::Value, RegistryError> {
+    table.get(key).ok_or_else(|| toml_syntax(&format!("missing field '{key}'")))
+}
+
+/// Returns the string field `key` of a TOML table.
+#[cfg(feature = "toml")]
+fn toml_str<'a>(table: &'a toml::Value, key: &str) -> Result<&'a str, RegistryError> {
+    toml_field(table, key)?.as_str().ok_or_else(|| toml_syntax(&format!("'{key}' must be a string")))
+}
+
+/// Returns the number field `key` of a TOML table, accepting integers.
+#[cfg(feature = "toml")]
+fn toml_number(table: &toml::Value, key: &str) -> Result<f64, RegistryError> {
+    let value = toml_field(table, key)?;
+    value
+        .as_float()
+        .or_else(|| value.as_integer().map(|n| n as f64))
+        .ok_or_else(|| toml_syntax(&format!("'{key}' must be a number")))
+}
+
+/// Returns the optional `aliases` array of a TOML table.
+#[cfg(feature = "toml")]
+fn toml_aliases(table: &toml::Value) -> Result<Vec<String>, RegistryError> {
+    let Some(aliases) = table.get("aliases") else {
+        return Ok(Vec::new());
+    };
+    let aliases = aliases.as_array().ok_or_else(|| toml_syntax("'aliases' must be an array"))?;
+    aliases
+        .iter()
+        .map(|alias| alias.as_str().map(str::to_string).ok_or_else(|| toml_syntax("aliases must be strings")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+    use crate::{TOLERANCE_EPSILON, TOLERANCE_ULPS};
+
+    const DEFINITIONS: &str = "\
+        # name    | dimension   | scale       | offset | aliases
+        gill      | volume      | 0.11829411825 | 0    | gi
+        fluid dram| volume      | 0.0036966911953125 |  | fl dr, drachm
+        leiden    | temperature | 1           | 20.15  | ld
+    ";
+
+    fn registry() -> UnitRegistry {
+        let mut registry = UnitRegistry::new();
+        assert_eq!(registry.load_str(DEFINITIONS), Ok(3));
+        registry
+    }
+
+    #[test]
+    // test built-in conversions match convert exactly
+    fn test_registry_builtin_matches_convert() {
+        let registry = UnitRegistry::new();
+        assert_eq!(registry.convert("liters", "gal", 1.0), Ok(0.2641));
+        assert_eq!(registry.convert("°C", "K", 70.0), Ok(343.15));
+    }
+
+    #[test]
+    // test conversions to and from runtime-defined units
+    fn test_registry_defined_units() {
+        let registry = registry();
+        let cups = registry.convert("gills", "cups", 4.0).unwrap();
+        assert!(approx_eq!(f64, cups, 2.0, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+        let drams = registry.convert("tbsp", "fl dr", 1.0).unwrap();
+        assert!(approx_eq!(f64, drams, 4.0, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+        let celsius = registry.convert("ld", "celsius", 253.0).unwrap();
+        assert!(approx_eq!(f64, celsius, 0.0, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+        let leiden = registry.convert("fahrenheit", "Leiden", 212.0).unwrap();
+        assert!(approx_eq!(f64, leiden, 353.0, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+    }
+
+    #[test]
+    // test prefixed units convert but never block a registered name
+    fn test_registry_prefixed_units() {
+        let mut registry = UnitRegistry::new();
+        assert_eq!(registry.convert("hL", "L", 1.0), Ok(100.0));
+        assert!(!registry.contains("hL"));
+        assert_eq!(registry.define(UnitDefinition::new("hL", Dimension::Volume, 10.0)), Ok(()));
+        assert_eq!(registry.convert("hL", "L", 1.0), Ok(10.0));
+    }
+
+    #[test]
+    // test lookup errors
+    fn test_registry_errors() {
+        let mut registry = registry();
+        assert_eq!(
+            registry.convert("gill", "kelvin", 1.0),
+            Err(RegistryError::DimensionMismatch { from: Dimension::Volume, to: Dimension::Temperature })
+        );
+        assert_eq!(registry.convert("firkin", "gill", 1.0), Err(RegistryError::UnknownUnit("firkin".to_string())));
+        assert_eq!(
+            registry.define(UnitDefinition::new("gallon", Dimension::Volume, 4.0)),
+            Err(RegistryError::DuplicateName("gallon".to_string()))
+        );
+        assert_eq!(
+            registry.define(UnitDefinition::new("nothing", Dimension::Volume, 0.0)),
+            Err(RegistryError::InvalidDefinition("nothing".to_string()))
+        );
+    }
+
+    #[test]
+    // test suggestions include runtime-defined and counting units once each
+    fn test_registry_suggestions() {
+        let mut registry = registry();
+        registry.load_str("batch | ratio | 2.5 | cups\nbatch | ratio | 45 | minutes").unwrap();
+        assert_eq!(registry.suggestions("Leidn"), vec!["leiden"]);
+        assert_eq!(registry.suggestions("bacth"), vec!["batch"]);
+        assert_eq!(registry.suggestions("galons"), vec!["gallons"]);
+        assert!(registry.suggestions("zz").is_empty());
+    }
+
+    #[test]
+    // test malformed definition lines report their line number
+    fn test_registry_syntax_errors() {
+        let mut registry = UnitRegistry::new();
+        assert!(matches!(
+            registry.load_str("\ngill | volume"),
+            Err(RegistryError::Syntax { line: 2, .. })
+        ));
+        assert!(matches!(
+            registry.load_str("gill | flavor | 1"),
+            Err(RegistryError::Syntax { line: 1, .. })
+        ));
+        assert!(matches!(
+            registry.load_str("gill | volume | lots"),
+            Err(RegistryError::Syntax { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    // test counting units convert through their ratios, including multi-step problems
+    fn test_registry_ratio_units() {
+        let mut registry = registry();
+        registry
+            .load_str(
+                "batch | ratio | 2.5 | cups | batches\n\
+                 batch | ratio | 45 | minutes\n\
+                 drip  | ratio | 0.05 | mL | drips",
+            )
+            .unwrap();
+        assert_eq!(registry.ratios().len(), 3);
+
+        // A faucet leaks 0.05 mL per drip: how many gallons in 10,000 drips?
+        let gallons = registry.convert("drips", "gallons", 10_000.0).unwrap();
+        assert!(approx_eq!(f64, gallons, 0.1321, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{gallons}");
+
+        // 10 cups of batter is 4 batches, which take 3 hours to bake
+        let batches = registry.convert("cups", "batch", 10.0).unwrap();
+        assert!(approx_eq!(f64, batches, 4.0, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+        let hours = registry.convert("batches", "h", batches).unwrap();
+        assert!(approx_eq!(f64, hours, 3.0, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+
+        // Counting units convert to each other through a shared dimension
+        let drips = registry.convert("batch", "drips", 1.0).unwrap();
+        assert!(approx_eq!(f64, drips, 11_829.411_8, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{drips}");
+        let batches = registry.convert("gills", "batches", 5.0).unwrap();
+        assert!(approx_eq!(f64, batches, 1.0, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+        assert_eq!(registry.convert("batches", "batch", 3.0), Ok(3.0));
+    }
+
+    #[test]
+    // test ratio unit errors
+    fn test_registry_ratio_errors() {
+        let mut registry = UnitRegistry::new();
+        registry.define_ratio(RatioUnit::new("batch", 2.5, Unit::Cups)).unwrap();
+        registry.define_ratio(RatioUnit::new("serving", 0.5, Unit::Cups)).unwrap();
+        assert_eq!(
+            registry.convert("batch", "kg", 1.0),
+            Err(RegistryError::NoRatio { from: "batch".to_string(), to: "kg".to_string() })
+        );
+        assert_eq!(registry.dimension("batches"), Err(RegistryError::CountingUnit("batches".to_string())));
+        assert_eq!(
+            registry.define_ratio(RatioUnit::new("batch", 600.0, Unit::Milliliters)),
+            Err(RegistryError::DuplicateName("batch".to_string()))
+        );
+        assert_eq!(
+            registry.define_ratio(RatioUnit::new("batch", 1.0, Unit::Hours).with_alias("serving")),
+            Err(RegistryError::DuplicateName("serving".to_string()))
+        );
+        assert_eq!(
+            registry.define_ratio(RatioUnit::new("cup", 2.0, Unit::Liters)),
+            Err(RegistryError::DuplicateName("cup".to_string()))
+        );
+        assert_eq!(
+            registry.define(UnitDefinition::new("batch", Dimension::Volume, 1.0)),
+            Err(RegistryError::DuplicateName("batch".to_string()))
+        );
+        assert_eq!(
+            registry.define_ratio(RatioUnit::new("fever", 2.0, Unit::Celsius)),
+            Err(RegistryError::InvalidDefinition("fever".to_string()))
+        );
+        assert!(matches!(registry.load_str("lap | ratio | 400"), Err(RegistryError::Syntax { line: 1, .. })));
+        assert!(matches!(registry.load_str("lap | ratio | 400 | furlongs"), Err(RegistryError::Syntax { line: 1, .. })));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    // test loading TOML definitions
+    fn test_registry_load_toml() {
+        let mut registry = UnitRegistry::new();
+        let text = r#"
+            [[unit]]
+            name = "gill"
+            dimension = "volume"
+            scale = 0.11829411825
+            aliases = ["gi"]
+
+            [[unit]]
+            name = "leiden degree"
+            dimension = "temperature"
+            scale = 1.0
+            offset = 20.15
+        "#;
+        assert_eq!(registry.load_toml_str(text), Ok(2));
+        let gills = registry.convert("gi", "gills", 2.0).unwrap();
+        assert!(approx_eq!(f64, gills, 2.0, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+        let kelvin = registry.convert("leiden degrees", "kelvin", 253.0).unwrap();
+        assert!(approx_eq!(f64, kelvin, 273.15, (TOLERANCE_EPSILON, TOLERANCE_ULPS)));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    // test loading TOML ratio tables, which mirror ratio lines
+    fn test_registry_load_toml_ratios() {
+        let mut registry = UnitRegistry::new();
+        let text = r#"
+            [[ratio]]
+            name = "batch"
+            amount = 2.5
+            unit = "cups"
+            aliases = ["lot"]
+
+            [[ratio]]
+            name = "batch"
+            amount = 45
+            unit = "minutes"
+        "#;
+        assert_eq!(registry.load_toml_str(text), Ok(2));
+        assert_eq!(registry.convert("cups", "lots", 10.0).unwrap(), 4.0);
+        assert_eq!(registry.convert("batches", "hours", 4.0).unwrap(), 3.0);
+
+        let mut lines = UnitRegistry::new();
+        lines.load_str("batch | ratio | 2.5 | cups | lot\nbatch | ratio | 45 | minutes").unwrap();
+        assert_eq!(lines.convert("lots", "cups", 3.0), registry.convert("lots", "cups", 3.0));
+
+        let missing = "[[ratio]]\nname = \"lap\"\namount = 400";
+        assert!(matches!(registry.load_toml_str(missing), Err(RegistryError::Syntax { line: 0, .. })));
+        let unknown = "[[ratio]]\nname = \"lap\"\namount = 400\nunit = \"furlongs\"";
+        assert!(matches!(registry.load_toml_str(unknown), Err(RegistryError::Syntax { line: 0, .. })));
+    }
+}
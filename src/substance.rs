@@ -0,0 +1,392 @@
+//! Volume ↔ mass conversions through the density of a named substance.
+//!
+//! Cooking worksheets ask questions such as "2 cups of flour in grams",
+//! which cannot be answered from the units alone. A [`Substance`] supplies
+//! the missing density, so [`convert_substance`] can bridge any volume unit
+//! and any mass unit:
+//!
+//! ```text
+//! mass = volume × density
+//! ```
+//!
+//! Densities of dry ingredients depend on how they are measured; the
+//! built-in table uses common culinary weight charts (spooned and leveled
+//! flour, packed brown sugar) given in grams per US cup. Water's density
+//! can also be taken at a temperature with [`Substance::water_at`].
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::explain::explain_operations;
+use crate::explain::number;
+use crate::{convert, explain, water_density, Dimension, Explanation, Operation, Unit, LITERS_PER_CUP};
+
+/// A substance with a known density, used to convert between volume and mass.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::Substance;
+///
+/// let flour: Substance = "flour".parse().unwrap();
+/// assert_eq!(flour.name(), "all-purpose flour");
+/// assert_eq!(Substance::WATER.density(), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Substance {
+    name: &'static str,
+    density: f64,
+    temperature: Option<(f64, Unit)>,
+}
+
+impl Substance {
+    /// Water, at 1 g/mL.
+    pub const WATER: Self = Self::new("water", 1.0);
+    /// Whole milk.
+    pub const MILK: Self = Self::new("milk", 1.03);
+    /// All-purpose flour, spooned and leveled.
+    pub const ALL_PURPOSE_FLOUR: Self = Self::per_cup("all-purpose flour", 125.0);
+    /// Whole wheat flour.
+    pub const WHOLE_WHEAT_FLOUR: Self = Self::per_cup("whole wheat flour", 120.0);
+    /// Granulated white sugar.
+    pub const GRANULATED_SUGAR: Self = Self::per_cup("granulated sugar", 200.0);
+    /// Brown sugar, packed.
+    pub const BROWN_SUGAR: Self = Self::per_cup("brown sugar", 213.0);
+    /// Powdered (confectioners') sugar.
+    pub const POWDERED_SUGAR: Self = Self::per_cup("powdered sugar", 120.0);
+    /// Butter.
+    pub const BUTTER: Self = Self::per_cup("butter", 227.0);
+    /// Vegetable oil.
+    pub const VEGETABLE_OIL: Self = Self::new("vegetable oil", 0.92);
+    /// Honey.
+    pub const HONEY: Self = Self::per_cup("honey", 340.0);
+    /// Table salt.
+    pub const TABLE_SALT: Self = Self::per_cup("table salt", 288.0);
+    /// Rolled oats.
+    pub const ROLLED_OATS: Self = Self::per_cup("rolled oats", 90.0);
+    /// Uncooked long-grain white rice.
+    pub const WHITE_RICE: Self = Self::per_cup("white rice", 185.0);
+    /// Unsweetened cocoa powder.
+    pub const COCOA_POWDER: Self = Self::per_cup("cocoa powder", 85.0);
+
+    /// Creates a substance from its density in grams per milliliter
+    /// (equivalently, kilograms per liter).
+    #[must_use]
+    pub const fn new(name: &'static str, density: f64) -> Self {
+        Self { name, density, temperature: None }
+    }
+
+    /// Creates a substance from the weight of one US cup, in grams, as
+    /// culinary weight charts list it.
+    #[must_use]
+    pub const fn per_cup(name: &'static str, grams: f64) -> Self {
+        Self::new(name, grams / (LITERS_PER_CUP * 1000.0))
+    }
+
+    /// Returns water at a temperature in any temperature unit, with its
+    /// density from [`water_density`].
+    ///
+    /// Returns `None` if `unit` is not a temperature unit or the temperature
+    /// is outside 0–100 °C.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustoleum::{convert_substance, Substance, Unit};
+    ///
+    /// let hot_water = Substance::water_at(80.0, Unit::Fahrenheit).unwrap();
+    /// assert_eq!(hot_water.to_string(), "water at 80 °F");
+    ///
+    /// let kilograms = convert_substance(Unit::Liters, Unit::Kilograms, 1.0, hot_water).unwrap();
+    /// assert!((kilograms - 0.9966).abs() < 1e-4);
+    /// ```
+    #[must_use]
+    pub fn water_at(temperature: f64, unit: Unit) -> Option<Self> {
+        let density = water_density(temperature, unit)?;
+        Some(Self { temperature: Some((temperature, unit)), ..Self::new("water", density) })
+    }
+
+    /// Returns every built-in substance.
+    #[must_use]
+    pub const fn all() -> &'static [Substance] {
+        &SUBSTANCES
+    }
+
+    /// Returns the substance's name, e.g. "all-purpose flour".
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the density in grams per milliliter (kilograms per liter).
+    #[must_use]
+    pub const fn density(self) -> f64 {
+        self.density
+    }
+
+    /// Returns the temperature the density was taken at, if any.
+    #[must_use]
+    pub const fn temperature(self) -> Option<(f64, Unit)> {
+        self.temperature
+    }
+}
+
+impl fmt::Display for Substance {
+    /// Formats the substance by name, with its temperature if any, e.g.
+    /// "water at 80 °F".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)?;
+        if let Some((temperature, unit)) = self.temperature {
+            write!(f, " at {} {}", number(temperature), unit.symbol())?;
+        }
+        Ok(())
+    }
+}
+
+/// Every built-in substance.
+const SUBSTANCES: [Substance; 14] = [
+    Substance::WATER,
+    Substance::MILK,
+    Substance::ALL_PURPOSE_FLOUR,
+    Substance::WHOLE_WHEAT_FLOUR,
+    Substance::GRANULATED_SUGAR,
+    Substance::BROWN_SUGAR,
+    Substance::POWDERED_SUGAR,
+    Substance::BUTTER,
+    Substance::VEGETABLE_OIL,
+    Substance::HONEY,
+    Substance::TABLE_SALT,
+    Substance::ROLLED_OATS,
+    Substance::WHITE_RICE,
+    Substance::COCOA_POWDER,
+];
+
+/// Other names accepted for the built-in substances.
+const ALIASES: [(&str, Substance); 12] = [
+    ("flour", Substance::ALL_PURPOSE_FLOUR),
+    ("ap flour", Substance::ALL_PURPOSE_FLOUR),
+    ("wheat flour", Substance::WHOLE_WHEAT_FLOUR),
+    ("sugar", Substance::GRANULATED_SUGAR),
+    ("white sugar", Substance::GRANULATED_SUGAR),
+    ("confectioners sugar", Substance::POWDERED_SUGAR),
+    ("icing sugar", Substance::POWDERED_SUGAR),
+    ("oil", Substance::VEGETABLE_OIL),
+    ("salt", Substance::TABLE_SALT),
+    ("oats", Substance::ROLLED_OATS),
+    ("rice", Substance::WHITE_RICE),
+    ("cocoa", Substance::COCOA_POWDER),
+];
+
+/// Error type for parsing a [`Substance`] from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubstanceParseError(pub String);
+
+impl fmt::Display for SubstanceParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown substance: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for SubstanceParseError {}
+
+impl FromStr for Substance {
+    type Err = SubstanceParseError;
+
+    /// Parses a built-in substance by name or alias, ignoring case,
+    /// hyphens, and apostrophes ("All Purpose Flour", "confectioners' sugar").
+    /// Water may be given a temperature, as in "water at 80 °F".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((name, temperature)) = s.to_lowercase().split_once(" at ") {
+            let err = || SubstanceParseError(s.to_string());
+            if name.parse::<Self>() != Ok(Self::WATER) {
+                return Err(err());
+            }
+            let temperature = temperature.trim();
+            let split = temperature
+                .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')))
+                .unwrap_or(temperature.len());
+            let value = temperature[..split].parse::<f64>().map_err(|_| err())?;
+            let unit = Unit::from_str(temperature[split..].trim()).map_err(|_| err())?;
+            return Self::water_at(value, unit).ok_or_else(err);
+        }
+
+        let key = |name: &str| {
+            let name = name.to_lowercase().replace(['-', '_'], " ").replace('\'', "");
+            name.split_whitespace().collect::<Vec<_>>().join(" ")
+        };
+        let wanted = key(s);
+        SUBSTANCES
+            .iter()
+            .map(|substance| (substance.name, *substance))
+            .chain(ALIASES)
+            .find(|(name, _)| key(name) == wanted)
+            .map(|(_, substance)| substance)
+            .ok_or_else(|| SubstanceParseError(s.to_string()))
+    }
+}
+
+/// Converts a quantity of a substance between volume and mass units.
+///
+/// Volume is converted to mass (or mass to volume) through the substance's
+/// density. Units of the same dimension convert exactly as
+/// [`convert`](crate::convert) does, whatever the substance. Returns `None`
+/// if either unit is neither a volume nor a mass.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{convert_substance, Substance, Unit};
+///
+/// let grams = convert_substance(Unit::Cups, Unit::Grams, 2.0, Substance::ALL_PURPOSE_FLOUR).unwrap();
+/// assert!((grams - 250.0).abs() < 1e-9);
+///
+/// assert_eq!(convert_substance(Unit::Cups, Unit::Kelvin, 2.0, Substance::WATER), None);
+/// ```
+#[must_use]
+pub fn convert_substance(from: Unit, to: Unit, value: f64, substance: Substance) -> Option<f64> {
+    match substance_operations(from, to, substance) {
+        Some(operations) => Some(operations.iter().fold(value, |value, operation| operation.apply(value))),
+        None => convert(from, to, value).filter(|_| is_bridged(from.dimension())),
+    }
+}
+
+/// Explains a conversion of a substance between volume and mass units.
+///
+/// The explanation names the substance and its density, and its result
+/// matches [`convert_substance`]. Returns `None` if the conversion is invalid.
+///
+/// # Example
+///
+/// ```rust
+/// use rustoleum::{explain_substance, Substance, Unit};
+///
+/// let explanation = explain_substance(Unit::Cups, Unit::Grams, 2.0, Substance::ALL_PURPOSE_FLOUR).unwrap();
+/// assert!(explanation.to_text().starts_with("Convert 2 cups of all-purpose flour to grams"));
+/// ```
+#[must_use]
+pub fn explain_substance(from: Unit, to: Unit, value: f64, substance: Substance) -> Option<Explanation> {
+    let explanation = match substance_operations(from, to, substance) {
+        Some(operations) => explain_operations(from, to, value, &operations),
+        None if is_bridged(from.dimension()) => explain(from, to, value)?,
+        None => return None,
+    };
+    Some(explanation.with_substance(substance))
+}
+
+/// Returns true for the dimensions a substance's density relates.
+fn is_bridged(dimension: Dimension) -> bool {
+    matches!(dimension, Dimension::Volume | Dimension::Mass)
+}
+
+/// Returns the operations for a volume ↔ mass conversion: into liters or
+/// kilograms, through the density, and out to the target unit. Returns
+/// `None` unless one unit is a volume and the other a mass.
+fn substance_operations(from: Unit, to: Unit, substance: Substance) -> Option<Vec<Operation>> {
+    let density = match (from.dimension(), to.dimension()) {
+        (Dimension::Volume, Dimension::Mass) => Operation::Multiply { numerator: substance.density, denominator: 1.0 },
+        (Dimension::Mass, Dimension::Volume) => Operation::Divide(substance.density),
+        _ => return None,
+    };
+    let mut operations = Vec::new();
+    if from.scale() != 1.0 {
+        operations.push(Operation::Multiply { numerator: from.scale(), denominator: 1.0 });
+    }
+    operations.push(density);
+    if to.scale() != 1.0 {
+        operations.push(Operation::Divide(to.scale()));
+    }
+    Some(operations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+    use crate::{TOLERANCE_EPSILON, TOLERANCE_ULPS};
+
+    #[test]
+    // test volume to mass and mass to volume through a density
+    fn test_convert_substance() {
+        let cases = [
+            (Unit::Cups, Unit::Grams, 2.0, Substance::ALL_PURPOSE_FLOUR, 250.0),
+            (Unit::Grams, Unit::Cups, 100.0, Substance::GRANULATED_SUGAR, 0.5),
+            (Unit::Tablespoons, Unit::Grams, 1.0, Substance::BUTTER, 14.1875),
+            (Unit::Liters, Unit::Kilograms, 2.0, Substance::WATER, 2.0),
+            (Unit::Gallons, Unit::Pounds, 1.0, Substance::WATER, 8.3454),
+            (Unit::Ounces, Unit::Milliliters, 12.0, Substance::HONEY, 236.7234),
+        ];
+        for (from, to, value, substance, expected) in cases {
+            let result = convert_substance(from, to, value, substance).unwrap();
+            assert!(
+                approx_eq!(f64, result, expected, (TOLERANCE_EPSILON, TOLERANCE_ULPS)),
+                "{value} {from} of {substance} -> {to}: {result}"
+            );
+        }
+    }
+
+    #[test]
+    // test same-dimension conversions ignore the substance and others are invalid
+    fn test_convert_substance_other_dimensions() {
+        assert_eq!(convert_substance(Unit::Liters, Unit::Gallons, 1.0, Substance::HONEY), Some(0.2641));
+        assert_eq!(convert_substance(Unit::Pounds, Unit::Kilograms, 1.0, Substance::HONEY), convert(Unit::Pounds, Unit::Kilograms, 1.0));
+        assert_eq!(convert_substance(Unit::Cups, Unit::Celsius, 1.0, Substance::WATER), None);
+        assert_eq!(convert_substance(Unit::Celsius, Unit::Kelvin, 1.0, Substance::WATER), None);
+    }
+
+    #[test]
+    // test parsing substances by name and alias
+    fn test_parse_substance() {
+        assert_eq!("flour".parse(), Ok(Substance::ALL_PURPOSE_FLOUR));
+        assert_eq!("All-Purpose Flour".parse(), Ok(Substance::ALL_PURPOSE_FLOUR));
+        assert_eq!("confectioners' sugar".parse(), Ok(Substance::POWDERED_SUGAR));
+        assert_eq!("sugar".parse(), Ok(Substance::GRANULATED_SUGAR));
+        for substance in Substance::all() {
+            assert_eq!(substance.name().parse(), Ok(*substance));
+        }
+        assert_eq!("sand".parse::<Substance>(), Err(SubstanceParseError("sand".to_string())));
+    }
+
+    #[test]
+    // test water at a temperature, e.g. "1 L of water at 80 °F"
+    fn test_water_at_temperature() {
+        let warm = Substance::water_at(80.0, Unit::Fahrenheit).unwrap();
+        assert_eq!("Water at 80 °F".parse(), Ok(warm));
+        assert_eq!("water at 80F".parse(), Ok(warm));
+        let kilograms = convert_substance(Unit::Liters, Unit::Kilograms, 1.0, warm).unwrap();
+        assert!(approx_eq!(f64, kilograms, 0.9966, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{kilograms}");
+
+        // Published: 958.35 kg/m³ at 100 °C, so a gallon weighs 3.6277 kg
+        let boiling = Substance::water_at(212.0, Unit::Fahrenheit).unwrap();
+        let kilograms = convert_substance(Unit::Gallons, Unit::Kilograms, 1.0, boiling).unwrap();
+        assert!(approx_eq!(f64, kilograms, 3.6277, (TOLERANCE_EPSILON, TOLERANCE_ULPS)), "{kilograms}");
+
+        let explanation = explain_substance(Unit::Liters, Unit::Kilograms, 1.0, warm).unwrap();
+        assert!(explanation.to_text().starts_with("Convert 1 liter of water at 80 °F to kilograms:\n"));
+
+        assert_eq!(Substance::water_at(150.0, Unit::Celsius), None);
+        assert!("water at 150 C".parse::<Substance>().is_err());
+        assert!("flour at 20 C".parse::<Substance>().is_err());
+        assert!("water at hot".parse::<Substance>().is_err());
+    }
+
+    #[test]
+    // test explanations name the substance and match the conversion
+    fn test_explain_substance() {
+        let explanation = explain_substance(Unit::Cups, Unit::Grams, 2.0, Substance::ALL_PURPOSE_FLOUR).unwrap();
+        assert_eq!(explanation.substance(), Some(Substance::ALL_PURPOSE_FLOUR));
+        assert_eq!(
+            Some(explanation.result()),
+            convert_substance(Unit::Cups, Unit::Grams, 2.0, Substance::ALL_PURPOSE_FLOUR)
+        );
+        assert_eq!(explanation.steps().len(), 3);
+        let text = explanation.to_text();
+        assert!(text.starts_with("Convert 2 cups of all-purpose flour to grams:\n"), "{text}");
+        assert!(text.contains("Density of all-purpose flour (0.5283 g/mL)"), "{text}");
+        assert!(explanation.to_markdown().contains("cups of all-purpose flour"));
+        assert!(explanation.to_latex().contains("\\text{cups of all-purpose flour}"));
+
+        let same = explain_substance(Unit::Cups, Unit::Tablespoons, 1.0, Substance::WATER).unwrap();
+        assert_eq!(same.result(), 16.0);
+        assert!(explain_substance(Unit::Cups, Unit::Kelvin, 1.0, Substance::WATER).is_none());
+    }
+}
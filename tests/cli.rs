@@ -0,0 +1,48 @@
+//! Grading through the `rustoleum` command-line tool.
+
+#![cfg(feature = "std")]
+
+use std::process::Command;
+
+/// Runs the command-line tool, returning its exit status and its standard
+/// output and error joined together.
+fn rustoleum(args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_rustoleum")).args(args).output().unwrap();
+    let text = String::from_utf8_lossy(&output.stdout).into_owned() + &String::from_utf8_lossy(&output.stderr);
+    (output.status.success(), text)
+}
+
+#[test]
+// test grading reads SI prefix symbols as the library and evaluator do
+fn test_grade_prefix_case() {
+    for args in [["Mg", "g", "1", "1000000"], ["ML", "L", "1", "1000000"], ["kL", "L", "1", "1000"], ["mg", "g", "500", "0.5"]] {
+        assert_eq!(rustoleum(&args), (true, "Answer: correct\n".to_string()), "{args:?}");
+    }
+    assert_eq!(rustoleum(&["eval", "1", "Mg", "in", "g"]), (true, "1000000 grams\n".to_string()));
+    assert_eq!(rustoleum(&["eval", "1", "kL", "in", "L"]), (true, "1000 liters\n".to_string()));
+}
+
+#[test]
+// test "in" is read as inches when a target clause follows it
+fn test_eval_inches_before_keyword() {
+    assert_eq!(rustoleum(&["eval", "1", "in", "in", "cm"]), (true, "2.54 centimeters\n".to_string()));
+}
+
+#[test]
+// test --explain is refused rather than ignored for prefixed and compound units
+fn test_explain_prefixed_rejected() {
+    for args in [["--explain", "ML", "L", "1", "1000000"], ["--explain", "m/s", "km/h", "1", "3.6"]] {
+        let (success, text) = rustoleum(&args);
+        assert!(!success, "{args:?}");
+        assert!(text.contains("--explain is not available for units with prefixes or operators"), "{text}");
+    }
+}
+
+#[test]
+// test a bare "N" is not read as degrees Newton inside compound units
+fn test_grade_bare_newton_symbol() {
+    let (success, text) = rustoleum(&["N/m^2", "Pa", "1", "1"]);
+    assert!(!success);
+    assert!(text.contains("Unknown unit: 'N'"), "{text}");
+    assert_eq!(rustoleum(&["°N", "°C", "33", "100"]), (true, "Answer: correct\n".to_string()));
+}
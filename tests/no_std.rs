@@ -0,0 +1,42 @@
+//! The core API as a `no_std` crate sees it.
+//!
+//! This crate is itself `#![no_std]`, so it only compiles against what the
+//! library provides without allocation. Run it against the `no_std` build
+//! with `cargo test --no-default-features --tests`.
+
+#![no_std]
+
+use rustoleum::{convert, fah_cel, grade, Converter, Decimal, Dimension, Grade, Unit, ABSOLUTE_ZERO_CELSIUS};
+
+#[test]
+// test temperature conversions in f32 and f64
+fn test_convert_temperatures() {
+    assert_eq!(convert(Unit::Celsius, Unit::Kelvin, 0.0), Some(ABSOLUTE_ZERO_CELSIUS));
+    let celsius = convert(Unit::Fahrenheit, Unit::Celsius, 212.0_f32).unwrap();
+    assert!((celsius - 100.0).abs() < 1e-4);
+    assert_eq!(fah_cel(-40.0_f32), -40.0);
+    assert_eq!(convert(Unit::Celsius, Unit::Liters, 1.0), None);
+}
+
+#[test]
+// test units, dimensions, and converters without allocating
+fn test_units_and_converters() {
+    assert_eq!(Unit::Rankine.dimension(), Dimension::Temperature);
+    assert_eq!(Unit::Rankine.symbol(), "°R");
+    assert!(Dimension::Temperature.units().any(|unit| unit == Unit::Newton));
+
+    const TO_KELVIN: Converter = match Converter::new(Unit::Fahrenheit, Unit::Kelvin) {
+        Ok(converter) => converter,
+        Err(_) => panic!("temperature units convert"),
+    };
+    assert!((TO_KELVIN.apply(32.0) - ABSOLUTE_ZERO_CELSIUS).abs() < 1e-9);
+    assert!(Converter::new(Unit::Kelvin, Unit::Grams).is_err());
+}
+
+#[test]
+// test grading rounds in decimal
+fn test_grade() {
+    let expected = convert(Unit::Liters, Unit::Gallons, 3.0).unwrap();
+    assert_eq!(grade(expected, 0.8_f32), Grade::Correct);
+    assert_eq!(grade(expected, Decimal::new(7, 1)), Grade::Incorrect);
+}